@@ -0,0 +1,170 @@
+use crate::conversion::cyrillic::convert_latn_to_cyrl;
+use crate::conversion::latin::{CONSONANTS, VOWELS};
+use crate::syllable::separate;
+use crate::util::{is_ainu_word, remove_acute_accent, SplitIntoWords};
+use crate::Script;
+use std::collections::HashMap;
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+/// Options for [`mark_accent_with`].
+#[derive(Debug, Clone)]
+pub struct AccentOptions {
+    /// Words whose accent does not follow the rule, keyed by the unaccented word in
+    /// lower case (e.g. `"hapo"` → `"hápo"`).
+    pub exceptions: HashMap<String, String>,
+    /// Script of the output, either [`Script::Latn`] or [`Script::Cyrl`].
+    pub script: Script,
+}
+
+impl Default for AccentOptions {
+    fn default() -> Self {
+        Self {
+            exceptions: HashMap::new(),
+            script: Script::Latn,
+        }
+    }
+}
+
+/// Put an acute accent on the vowel of the accented syllable of a word.
+fn accent_word(word: &str, exceptions: &HashMap<String, String>) -> String {
+    if remove_acute_accent(word) != word.nfc().collect::<String>() {
+        return word.to_owned();
+    }
+    if let Some(exception) = exceptions.get(&word.to_lowercase()) {
+        // Copy the case of each letter of the word onto the exception
+        let mut uppercase = word.chars().map(char::is_uppercase);
+        return exception
+            .nfd()
+            .flat_map(|c| {
+                let upper = !is_combining_mark(c) && uppercase.next() == Some(true);
+                match upper {
+                    true => c.to_uppercase().collect::<Vec<char>>(),
+                    false => vec![c],
+                }
+            })
+            .nfc()
+            .collect();
+    }
+
+    let lower = word.to_lowercase();
+    let syllables = separate(&lower);
+    let first_is_closed = syllables
+        .first()
+        .and_then(|syllable| syllable.chars().last())
+        .is_some_and(|c| CONSONANTS.contains(c));
+
+    // Each syllable has exactly one vowel, so the n-th syllable is the n-th vowel
+    let vowel_count = lower.chars().filter(|c| VOWELS.contains(*c)).count();
+    let accented_vowel = if first_is_closed || vowel_count < 2 {
+        0
+    } else {
+        1
+    };
+
+    let mut vowels_seen = 0;
+    let mut result = String::new();
+    for (c, c_lower) in word.chars().zip(lower.chars()) {
+        result.push(c);
+        if VOWELS.contains(c_lower) {
+            if vowels_seen == accented_vowel {
+                result.push('\u{301}');
+            }
+            vowels_seen += 1;
+        }
+    }
+
+    result.nfc().collect()
+}
+
+/// Mark the pitch accent of romanized Ainu words
+///
+/// The accent is put on the first syllable if it is closed and on the second syllable
+/// otherwise, as in most Hokkaido dialects. Words that already carry an accent are left
+/// as they are.
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+///
+/// # Returns
+///
+/// * `String` - The text with an acute accent on the accented vowel of every word.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::mark_accent;
+/// assert_eq!(mark_accent("aynu"), "áynu");
+/// assert_eq!(mark_accent("sapa"), "sapá");
+/// assert_eq!(mark_accent("ku=kor cise"), "ku=kór cisé");
+/// ```
+pub fn mark_accent(latn: &str) -> String {
+    mark_accent_with(latn, &AccentOptions::default())
+}
+
+/// Mark the pitch accent of romanized Ainu words with the given options
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+/// * `options` - Accent options, see [`AccentOptions`].
+///
+/// # Returns
+///
+/// * `String` - The accented text in the script given by the options.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{mark_accent_with, AccentOptions, Script};
+/// let mut options = AccentOptions::default();
+/// options.exceptions.insert("hapo".to_owned(), "hápo".to_owned());
+/// assert_eq!(mark_accent_with("hapo sapa", &options), "hápo sapá");
+///
+/// options.script = Script::Cyrl;
+/// assert_eq!(mark_accent_with("aynu", &options), "а́йну");
+/// ```
+pub fn mark_accent_with(latn: &str, options: &AccentOptions) -> String {
+    let accented = latn
+        .split_into_words()
+        .into_iter()
+        .map(|word| {
+            if is_ainu_word(&word) {
+                accent_word(&word, &options.exceptions)
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("");
+
+    match options.script {
+        Script::Cyrl => convert_latn_to_cyrl(&accented),
+        _ => accented,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mark_accent() {
+        assert_eq!(mark_accent("irankarapte"), "iránkarapte");
+        assert_eq!(mark_accent("Kamuy"), "Kamúy");
+        assert_eq!(mark_accent("pet"), "pét");
+        assert_eq!(mark_accent("ne"), "né");
+        assert_eq!(mark_accent("cápe"), "cápe");
+    }
+
+    #[test]
+    fn keeps_case_of_exceptions() {
+        let mut options = AccentOptions::default();
+        options
+            .exceptions
+            .insert("hapo".to_owned(), "hápo".to_owned());
+        assert_eq!(mark_accent_with("Hapo", &options), "Hápo");
+        assert_eq!(mark_accent_with("HAPO", &options), "HÁPO");
+    }
+}
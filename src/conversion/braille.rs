@@ -0,0 +1,321 @@
+use crate::conversion::katakana::{convert_kana_to_latn, convert_latn_to_kana};
+use crate::util::to_katakana;
+use unicode_normalization::UnicodeNormalization;
+
+/// Cells of the full-size kana in Japanese braille (tenji).
+const CELLS: [(char, char); 50] = [
+    ('ア', '⠁'),
+    ('イ', '⠃'),
+    ('ウ', '⠉'),
+    ('エ', '⠋'),
+    ('オ', '⠊'),
+    ('カ', '⠡'),
+    ('キ', '⠣'),
+    ('ク', '⠩'),
+    ('ケ', '⠫'),
+    ('コ', '⠪'),
+    ('サ', '⠱'),
+    ('シ', '⠳'),
+    ('ス', '⠹'),
+    ('セ', '⠻'),
+    ('ソ', '⠺'),
+    ('タ', '⠕'),
+    ('チ', '⠗'),
+    ('ツ', '⠝'),
+    ('テ', '⠟'),
+    ('ト', '⠞'),
+    ('ナ', '⠅'),
+    ('ニ', '⠇'),
+    ('ヌ', '⠍'),
+    ('ネ', '⠏'),
+    ('ノ', '⠎'),
+    ('ハ', '⠥'),
+    ('ヒ', '⠧'),
+    ('フ', '⠭'),
+    ('ヘ', '⠯'),
+    ('ホ', '⠮'),
+    ('マ', '⠵'),
+    ('ミ', '⠷'),
+    ('ム', '⠽'),
+    ('メ', '⠿'),
+    ('モ', '⠾'),
+    ('ヤ', '⠌'),
+    ('ユ', '⠬'),
+    ('ヨ', '⠜'),
+    ('ラ', '⠑'),
+    ('リ', '⠓'),
+    ('ル', '⠙'),
+    ('レ', '⠛'),
+    ('ロ', '⠚'),
+    ('ワ', '⠄'),
+    ('ヰ', '⠆'),
+    ('ヱ', '⠖'),
+    ('ヲ', '⠔'),
+    ('ン', '⠴'),
+    ('ッ', '⠂'),
+    ('ー', '⠒'),
+];
+
+/// Kana pairs written with a yōon (⠈) or gōyōon (⠢) prefix. `ト゚` and `トゥ` share a
+/// spelling, which is read back as `ト゚`.
+const DIGRAPHS: [(&str, &str); 11] = [
+    ("ㇷ゚", "⠸⠠⠭"),
+    ("ト゚", "⠢⠝"),
+    ("トゥ", "⠢⠝"),
+    ("チャ", "⠈⠕"),
+    ("チュ", "⠈⠝"),
+    ("チョ", "⠈⠞"),
+    ("チェ", "⠈⠟"),
+    ("イェ", "⠈⠋"),
+    ("ウィ", "⠢⠃"),
+    ("ウェ", "⠢⠋"),
+    ("ウォ", "⠢⠊"),
+];
+
+/// Small kana and the full-size kana whose cell follows [`SMALL_KANA_PREFIX`].
+const SMALL_KANA: [(char, char); 24] = [
+    ('ㇰ', 'ク'),
+    ('ㇱ', 'シ'),
+    ('ㇲ', 'ス'),
+    ('ㇳ', 'ト'),
+    ('ㇴ', 'ヌ'),
+    ('ㇵ', 'ハ'),
+    ('ㇶ', 'ヒ'),
+    ('ㇷ', 'フ'),
+    ('ㇸ', 'ヘ'),
+    ('ㇹ', 'ホ'),
+    ('ㇺ', 'ム'),
+    ('ㇻ', 'ラ'),
+    ('ㇼ', 'リ'),
+    ('ㇽ', 'ル'),
+    ('ㇾ', 'レ'),
+    ('ㇿ', 'ロ'),
+    ('ァ', 'ア'),
+    ('ィ', 'イ'),
+    ('ゥ', 'ウ'),
+    ('ェ', 'エ'),
+    ('ォ', 'オ'),
+    ('ャ', 'ヤ'),
+    ('ュ', 'ユ'),
+    ('ョ', 'ヨ'),
+];
+
+/// Prefix of a small kana (dots 4-5-6), followed by the cell of the full-size kana.
+const SMALL_KANA_PREFIX: char = '⠸';
+/// Dakuten prefix (dot 5)
+const DAKUTEN_PREFIX: char = '⠐';
+/// Handakuten prefix (dot 6)
+const HANDAKUTEN_PREFIX: char = '⠠';
+
+const PUNCTUATION: [(char, char); 5] = [
+    ('。', '⠲'),
+    ('、', '⠰'),
+    ('？', '⠢'),
+    ('！', '⠖'),
+    ('　', '⠀'),
+];
+
+fn cell_of(kana: char) -> Option<char> {
+    CELLS
+        .iter()
+        .find(|(k, _)| *k == kana)
+        .map(|(_, cell)| *cell)
+}
+
+fn kana_of(cell: char) -> Option<char> {
+    CELLS
+        .iter()
+        .find(|(_, c)| *c == cell)
+        .map(|(kana, _)| *kana)
+}
+
+/// Convert Katakana to Japanese braille (tenji)
+///
+/// Kana are written in the Unicode Braille Patterns block following the Japanese
+/// braille conventions: dakuten and handakuten prefixes, yōon and gōyōon prefixes for
+/// `チャ`, `イェ`, `ウィ`, `ト゚`, etc. Small Ainu kana (`ㇰ`, `ㇷ゚`, `ㇻ`…) are
+/// written as the small-kana prefix ⠸ followed by the cell of the full-size kana.
+/// Spaces become the blank cell ⠀.
+///
+/// # Arguments
+///
+/// * `kana` - A string slice that holds the Katakana text.
+///
+/// # Returns
+///
+/// * `String` - The braille representation of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_kana_to_braille;
+/// assert_eq!(convert_kana_to_braille("アイヌ"), "⠁⠃⠍");
+/// assert_eq!(convert_kana_to_braille("イランカラㇷ゚テ"), "⠃⠑⠴⠡⠑⠸⠠⠭⠟");
+/// ```
+pub fn convert_kana_to_braille(kana: &str) -> String {
+    let kana: Vec<char> = kana
+        .chars()
+        .map(|c| match c {
+            ' ' => '　',
+            // Hiragana are looked up through the corresponding katakana
+            _ => to_katakana(c),
+        })
+        .nfc()
+        .collect();
+
+    let mut result = String::new();
+    let mut i = 0;
+    while i < kana.len() {
+        let pair: String = kana[i..kana.len().min(i + 2)].iter().collect();
+        if let Some((_, cells)) = DIGRAPHS.iter().find(|(digraph, _)| *digraph == pair) {
+            result.push_str(cells);
+            i += 2;
+            continue;
+        }
+
+        let c = kana[i];
+        i += 1;
+
+        if let Some((_, full)) = SMALL_KANA.iter().find(|(small, _)| *small == c) {
+            result.push(SMALL_KANA_PREFIX);
+            result.extend(cell_of(*full));
+        } else if let Some(cell) = cell_of(c) {
+            result.push(cell);
+        } else if let Some((_, cell)) = PUNCTUATION.iter().find(|(p, _)| *p == c) {
+            result.push(*cell);
+        } else {
+            let decomposed: Vec<char> = std::iter::once(c).nfd().collect();
+            match decomposed[..] {
+                [base, '\u{3099}'] if cell_of(base).is_some() => {
+                    result.push(DAKUTEN_PREFIX);
+                    result.extend(cell_of(base));
+                }
+                [base, '\u{309A}'] if cell_of(base).is_some() => {
+                    result.push(HANDAKUTEN_PREFIX);
+                    result.extend(cell_of(base));
+                }
+                _ => result.push(c),
+            }
+        }
+    }
+
+    result
+}
+
+/// Convert Japanese braille (tenji) to Katakana
+///
+/// This reverses [`convert_kana_to_braille`]. The gōyōon prefix ⠢ on its own is read
+/// as `？`, and ⠖ as `！` rather than the obsolete `ヱ`.
+///
+/// # Arguments
+///
+/// * `braille` - A string slice that holds the braille text.
+///
+/// # Returns
+///
+/// * `String` - The Katakana representation of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_braille_to_kana;
+/// assert_eq!(convert_braille_to_kana("⠃⠑⠴⠡⠑⠸⠠⠭⠟"), "イランカラㇷ゚テ");
+/// ```
+pub fn convert_braille_to_kana(braille: &str) -> String {
+    let cells: Vec<char> = braille.chars().collect();
+
+    let mut result = String::new();
+    let mut i = 0;
+    while i < cells.len() {
+        let rest: String = cells[i..].iter().collect();
+        if let Some((kana, pattern)) = DIGRAPHS
+            .iter()
+            .find(|(_, pattern)| rest.starts_with(pattern))
+        {
+            result.push_str(kana);
+            i += pattern.chars().count();
+            continue;
+        }
+
+        let c = cells[i];
+        let next = cells.get(i + 1).copied().and_then(kana_of);
+        i += 1;
+
+        match (c, next) {
+            (SMALL_KANA_PREFIX, Some(full)) => {
+                match SMALL_KANA.iter().find(|(_, f)| *f == full) {
+                    Some((small, _)) => result.push(*small),
+                    None => result.push(full),
+                }
+                i += 1;
+            }
+            (DAKUTEN_PREFIX, Some(base)) => {
+                result.extend([base, '\u{3099}'].into_iter().nfc());
+                i += 1;
+            }
+            (HANDAKUTEN_PREFIX, Some(base)) => {
+                result.extend([base, '\u{309A}'].into_iter().nfc());
+                i += 1;
+            }
+            _ => match PUNCTUATION.iter().find(|(_, cell)| *cell == c) {
+                Some((punctuation, _)) => result.push(*punctuation),
+                None => result.push(kana_of(c).unwrap_or(c)),
+            },
+        }
+    }
+
+    result
+}
+
+/// Convert romanized Ainu to Japanese braille (tenji) through Katakana
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_latn_to_braille;
+/// assert_eq!(convert_latn_to_braille("kamuy"), "⠡⠽⠃");
+/// ```
+pub fn convert_latn_to_braille(latn: &str) -> String {
+    convert_kana_to_braille(&convert_latn_to_kana(latn))
+}
+
+/// Convert Japanese braille (tenji) to romanized Ainu through Katakana
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_braille_to_latn;
+/// assert_eq!(convert_braille_to_latn("⠳⠸⠑"), "sir");
+/// ```
+pub fn convert_braille_to_latn(braille: &str) -> String {
+    convert_kana_to_latn(&convert_braille_to_kana(braille))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_ainu_kana() {
+        for kana in [
+            "チセ",
+            "ト゚ㇺ",
+            "ウォㇱ",
+            "ペウレㇷ゚",
+            "カムイ　ネ。",
+            "ウェンカムイ",
+        ] {
+            assert_eq!(
+                convert_braille_to_kana(&convert_kana_to_braille(kana)),
+                kana
+            );
+        }
+    }
+
+    #[test]
+    fn reads_hiragana_and_voiced_kana() {
+        assert_eq!(convert_kana_to_braille("がっこう"), "⠐⠡⠂⠪⠉");
+        assert_eq!(convert_kana_to_braille("トゥ"), "⠢⠝");
+        assert_eq!(convert_braille_to_kana("⠐⠡⠂⠪⠉"), "ガッコウ");
+    }
+}
@@ -1,5 +1,83 @@
+use crate::conversion::latin::{GlottalStop, DEFAULT_HIATUS_MARKS, GLOTTAL_STOPS, HIATUS_MARKS};
+use crate::dictionary::Dictionary;
+use crate::language::identify_word;
+use crate::numeral::expand_numbers;
+use crate::util::{is_ainu_word, remove_acute_accent, SplitIntoWords};
+use crate::Script;
 use unicode_normalization::UnicodeNormalization;
 
+/// Spelling conventions of the Ainu Cyrillic script.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CyrillicConvention {
+    /// `c` is `ц`, `e` is `э` and `ye` is `е` everywhere (`maciya` → `мация`).
+    #[default]
+    Standard,
+    /// The convention of Russian-language publications: `c` is `ч`, and as in
+    /// Russian, `e` is `е` after a consonant and `э` elsewhere, while `ye` is `ье`
+    /// after a consonant and `е` elsewhere (`maciya` → `мачия`, `pe` → `пе`).
+    Russian,
+}
+
+/// Options for [`convert_latn_to_cyrl_with`] and [`convert_cyrl_to_latn_with`].
+#[derive(Debug, Clone, Default)]
+pub struct CyrlOptions {
+    /// How to write the glottal stop. `’`, `'` and `ʼ` are all written `ъ` in Cyrillic
+    /// (or dropped if the policy is [`GlottalStop::Omitted`]), and `ъ` and the glottal
+    /// stop after `й` are romanized according to the policy.
+    pub glottal_stop: GlottalStop,
+    /// Spelling convention of the Cyrillic text.
+    pub convention: CyrillicConvention,
+    /// Read `.` between two letters as a hiatus mark (`a.inu` → `аину`) rather than as
+    /// a full stop. `·` is always read as a hiatus mark.
+    pub dot_hiatus_mark: bool,
+    /// Spell out numbers written with digits as Ainu numerals (`2` → `ту`).
+    pub expand_numbers: bool,
+    /// House spellings looked up before the rules, word by word. Entries without a
+    /// Cyrillic spelling are ignored.
+    pub dictionary: Dictionary,
+    /// Keep words that are not Ainu, such as Japanese glosses and Russian commentary,
+    /// as they are (see [`identify_word`](crate::identify_word)).
+    pub skip_non_ainu: bool,
+}
+
+/// Convert a text word by word: words found in the dictionary take its spelling,
+/// non-Ainu words are kept if asked, and the rest is converted with `convert` under
+/// options without the dictionary.
+fn convert_words(
+    words: Vec<String>,
+    from: Script,
+    to: Script,
+    options: &CyrlOptions,
+    convert: fn(&str, &CyrlOptions) -> String,
+) -> String {
+    let rules = CyrlOptions {
+        dictionary: Dictionary::new(),
+        skip_non_ainu: false,
+        ..options.clone()
+    };
+    words
+        .into_iter()
+        .map(|word| {
+            if let Some(spelling) = options.dictionary.lookup(&word, from, to) {
+                spelling
+            } else if is_ainu_word(&word)
+                && options.skip_non_ainu
+                && !identify_word(&word).may_be_ainu()
+            {
+                word
+            } else {
+                convert(&word, &rules)
+            }
+        })
+        .collect()
+}
+
+/// Latin consonants after which the Russian convention writes `е` for `e`.
+const LATN_CONSONANTS: &str = "ptckmnshwr";
+
+/// Cyrillic consonants after which the Russian convention reads `е` as `e`.
+const CYRL_CONSONANTS: &str = "птцчкмнсхвр";
+
 /// Convert romanized Ainu to Cyrillic
 ///
 /// # Arguments
@@ -18,22 +96,108 @@ use unicode_normalization::UnicodeNormalization;
 /// assert_eq!(cyrl, "айну");
 /// ```
 pub fn convert_latn_to_cyrl(latn: &str) -> String {
+    convert_latn_to_cyrl_with(latn, &CyrlOptions::default())
+}
+
+/// Convert romanized Ainu to Cyrillic with the given options
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu word.
+/// * `options` - Conversion options, see [`CyrlOptions`].
+///
+/// # Returns
+///
+/// * `String` - The Cyrillic representation of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_latn_to_cyrl_with, CyrillicConvention, CyrlOptions, GlottalStop};
+/// let options = CyrlOptions::default();
+/// assert_eq!(convert_latn_to_cyrl_with("a’e", &options), "аъэ");
+/// assert_eq!(convert_latn_to_cyrl_with("a'e", &options), "аъэ");
+/// assert_eq!(convert_latn_to_cyrl_with("y’upi", &options), "йупи");
+/// assert_eq!(convert_latn_to_cyrl_with("a·inu", &options), "аину");
+/// assert_eq!(convert_latn_to_cyrl_with("aynu.ne", &options), "айну.нэ");
+///
+/// let options = CyrlOptions {
+///     glottal_stop: GlottalStop::Omitted,
+///     ..Default::default()
+/// };
+/// assert_eq!(convert_latn_to_cyrl_with("a'e", &options), "аэ");
+///
+/// let options = CyrlOptions {
+///     convention: CyrillicConvention::Russian,
+///     ..Default::default()
+/// };
+/// assert_eq!(convert_latn_to_cyrl_with("maciya", &options), "мачия");
+/// assert_eq!(convert_latn_to_cyrl_with("eper", &options), "эпер");
+/// ```
+pub fn convert_latn_to_cyrl_with(latn: &str, options: &CyrlOptions) -> String {
+    let hiatus_marks = match options.dot_hiatus_mark {
+        true => HIATUS_MARKS,
+        false => DEFAULT_HIATUS_MARKS,
+    };
+
+    if !options.dictionary.is_empty() || options.skip_non_ainu {
+        let words = latn.split_into_words_with_hiatus(hiatus_marks);
+        return convert_words(
+            words,
+            Script::Latn,
+            Script::Cyrl,
+            options,
+            convert_latn_to_cyrl_with,
+        );
+    }
+
     let mut result = String::new();
+    let latn = match options.expand_numbers {
+        true => expand_numbers(latn, Script::Latn),
+        false => latn.to_owned(),
+    };
     let latn = latn.nfd().collect::<String>();
     let mut chars = latn.chars().peekable();
+    let russian = options.convention == CyrillicConvention::Russian;
+    let mut previous_lower = None;
 
     while let Some(current_char) = chars.next() {
         let current_lower = current_char.to_lowercase().next().unwrap();
+        let after_y = previous_lower == Some('y');
+        let after_letter = previous_lower.is_some_and(char::is_alphabetic);
+        let after_consonant = previous_lower.is_some_and(|c| LATN_CONSONANTS.contains(c));
+        previous_lower = Some(current_lower);
+
+        // Cyrillic needs no hiatus mark: `a·i` is аи and `ay` is ай
+        if hiatus_marks.contains(current_char)
+            && after_letter
+            && chars.peek().is_some_and(|c| c.is_alphabetic())
+        {
+            continue;
+        }
+
+        if GLOTTAL_STOPS.contains(current_char) {
+            // After й the vowel is already written separately (йу, not ю)
+            let followed_by_vowel = chars
+                .peek()
+                .is_some_and(|&c| "aiueo".contains(c.to_ascii_lowercase()));
+            if options.glottal_stop != GlottalStop::Omitted && !(after_y && followed_by_vowel) {
+                result.push('ъ');
+            }
+            continue;
+        }
 
         let cyrl: Option<&str> = match chars.peek() {
             Some(&next_char) if current_lower == 'y' && "uaoe".contains(next_char) => {
                 let next_lower = next_char.to_lowercase().next().unwrap();
                 // Consume the next character
                 chars.next();
+                previous_lower = Some(next_lower);
                 match (current_lower, next_lower) {
                     ('y', 'u') => Some("ю"),
                     ('y', 'a') => Some("я"),
                     ('y', 'o') => Some("ё"),
+                    ('y', 'e') if russian && after_consonant => Some("ье"),
                     ('y', 'e') => Some("е"),
                     _ => unreachable!(),
                 }
@@ -42,11 +206,13 @@ pub fn convert_latn_to_cyrl(latn: &str) -> String {
                 'a' => Some("а"),
                 'i' => Some("и"),
                 'u' => Some("у"),
+                'e' if russian && after_consonant => Some("е"),
                 'e' => Some("э"),
                 'o' => Some("о"),
                 'k' => Some("к"),
                 's' => Some("с"),
                 't' => Some("т"),
+                'c' if russian => Some("ч"),
                 'c' => Some("ц"),
                 'h' => Some("х"),
                 'm' => Some("м"),
@@ -55,8 +221,6 @@ pub fn convert_latn_to_cyrl(latn: &str) -> String {
                 'r' => Some("р"),
                 'w' => Some("в"),
                 'y' => Some("й"),
-                '\'' => Some("ъ"),
-                '’' => Some(""),
                 _ => None,
             },
         };
@@ -78,6 +242,41 @@ pub fn convert_latn_to_cyrl(latn: &str) -> String {
     result
 }
 
+/// Convert romanized Ainu to Russian practical Cyrillic
+///
+/// This is a one-way transcription for Ainu names in Russian prose rather than the
+/// linguistic Cyrillic script: `c` is `ч`, `e` is `е` after a consonant, glottal
+/// stops, accents and morpheme boundaries (`=`) are dropped, and `yi` is `и`.
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+///
+/// # Returns
+///
+/// * `String` - The Russian practical transcription of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_latn_to_cyrl_practical;
+/// assert_eq!(convert_latn_to_cyrl_practical("Kamuy cisé"), "Камуй чисе");
+/// assert_eq!(convert_latn_to_cyrl_practical("ku=kor a'e"), "кукор аэ");
+/// ```
+pub fn convert_latn_to_cyrl_practical(latn: &str) -> String {
+    let latn = remove_acute_accent(&latn.replace('=', ""));
+    let options = CyrlOptions {
+        glottal_stop: GlottalStop::Omitted,
+        convention: CyrillicConvention::Russian,
+        ..Default::default()
+    };
+
+    convert_latn_to_cyrl_with(&latn, &options)
+        .replace("йи", "и")
+        .replace("Йи", "И")
+        .replace("ЙИ", "И")
+}
+
 /// Convert Cyrillic to romanized Ainu
 ///
 /// # Arguments
@@ -96,29 +295,90 @@ pub fn convert_latn_to_cyrl(latn: &str) -> String {
 /// assert_eq!(latn, "aynu");
 /// ```
 pub fn convert_cyrl_to_latn(cyrl: &str) -> String {
+    convert_cyrl_to_latn_with(cyrl, &CyrlOptions::default())
+}
+
+/// Convert Cyrillic to romanized Ainu with the given options
+///
+/// # Arguments
+///
+/// * `cyrl` - A string slice that holds the Cyrillic word.
+/// * `options` - Conversion options, see [`CyrlOptions`].
+///
+/// # Returns
+///
+/// * `String` - The romanized Ainu representation of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_cyrl_to_latn_with, CyrillicConvention, CyrlOptions, GlottalStop};
+/// let options = CyrlOptions::default();
+/// assert_eq!(convert_cyrl_to_latn_with("аъэ", &options), "a’e");
+/// assert_eq!(convert_cyrl_to_latn_with("йупи", &options), "y’upi");
+///
+/// let options = CyrlOptions {
+///     glottal_stop: GlottalStop::Apostrophe,
+///     ..Default::default()
+/// };
+/// assert_eq!(convert_cyrl_to_latn_with("аъэ", &options), "a'e");
+/// assert_eq!(convert_cyrl_to_latn_with("йупи", &options), "y'upi");
+///
+/// let options = CyrlOptions {
+///     convention: CyrillicConvention::Russian,
+///     ..Default::default()
+/// };
+/// assert_eq!(convert_cyrl_to_latn_with("мачия", &options), "maciya");
+/// assert_eq!(convert_cyrl_to_latn_with("эпер", &options), "eper");
+/// assert_eq!(convert_cyrl_to_latn_with("еса", &options), "yesa");
+/// ```
+pub fn convert_cyrl_to_latn_with(cyrl: &str, options: &CyrlOptions) -> String {
+    if !options.dictionary.is_empty() || options.skip_non_ainu {
+        let words = cyrl.split_into_words();
+        return convert_words(
+            words,
+            Script::Cyrl,
+            Script::Latn,
+            options,
+            convert_cyrl_to_latn_with,
+        );
+    }
+
+    let glottal_stop = options.glottal_stop.as_str();
+    let russian = options.convention == CyrillicConvention::Russian;
     let mut result = String::new();
     let mut chars = cyrl.chars().peekable();
+    let mut previous_lower = None;
 
     while let Some(current_char) = chars.next() {
         let current_lower = current_char.to_lowercase().next().unwrap();
+        let after_consonant = previous_lower.is_some_and(|c| CYRL_CONSONANTS.contains(c));
+        previous_lower = Some(current_lower);
 
-        let cyrl: Option<&str> = match chars.peek() {
+        let cyrl: Option<String> = match chars.peek() {
+            Some(&'е' | &'Е') if russian && current_lower == 'ь' => {
+                chars.next();
+                Some("ye".to_owned())
+            }
             Some(&next_char) if current_lower == 'й' && "уаоэ".contains(next_char) => {
                 let next_lower = next_char.to_lowercase().next().unwrap();
                 // Consume the next character
                 chars.next();
-                match next_lower {
-                    'у' => Some("y’u"),
-                    'а' => Some("y’a"),
-                    'о' => Some("y’o"),
-                    'э' => Some("y’e"),
+                let vowel = match next_lower {
+                    'у' => "u",
+                    'а' => "a",
+                    'о' => "o",
+                    'э' => "e",
                     _ => unreachable!(),
-                }
+                };
+                Some(format!("y{}{}", glottal_stop, vowel))
             }
             _ => match current_lower {
+                'ъ' => Some(glottal_stop),
                 'ю' => Some("yu"),
                 'я' => Some("ya"),
                 'ё' => Some("yo"),
+                'е' if russian && after_consonant => Some("e"),
                 'е' => Some("ye"),
                 'а' => Some("a"),
                 'и' => Some("i"),
@@ -129,6 +389,7 @@ pub fn convert_cyrl_to_latn(cyrl: &str) -> String {
                 'с' => Some("s"),
                 'т' => Some("t"),
                 'ц' => Some("c"),
+                'ч' if russian => Some("c"),
                 'х' => Some("h"),
                 'м' => Some("m"),
                 'н' => Some("n"),
@@ -136,12 +397,12 @@ pub fn convert_cyrl_to_latn(cyrl: &str) -> String {
                 'р' => Some("r"),
                 'в' => Some("w"),
                 'й' => Some("y"),
-                'ъ' => Some("'"),
                 'ь' => None,
                 '’' => None,
                 ' ' => None,
                 _ => None,
-            },
+            }
+            .map(str::to_owned),
         };
 
         // If the original character was uppercase, convert the result to uppercase
@@ -157,5 +418,9 @@ pub fn convert_cyrl_to_latn(cyrl: &str) -> String {
         };
         result.push_str(&cyrl_result);
     }
-    result.nfc().collect::<String>()
+    let result = result.nfc().collect::<String>();
+    match options.expand_numbers {
+        true => expand_numbers(&result, Script::Latn),
+        false => result,
+    }
 }
@@ -0,0 +1,238 @@
+use crate::conversion::katakana::convert_kana_to_latn;
+use crate::conversion::latin::{CONSONANTS, GLOTTAL_STOPS, HIATUS_MARKS, VOWELS};
+use crate::syllable::separate;
+use crate::util::{is_ainu_word, match_case, remove_acute_accent, SplitIntoWords};
+use unicode_normalization::UnicodeNormalization;
+
+/// Convert romanized Ainu to Japanese Hepburn-style practical romanization
+///
+/// This is the spelling of official Hokkaido place names and personal names: syllables
+/// are spelled as in Hepburn (`si` → `shi`, `ci` → `chi`, `tu` → `tsu`, `hu` → `fu`),
+/// glides become vowels (`kamuy` → `kamui`), a plosive coda before a consonant doubles
+/// it as a Japanese `ッ` would (`satporo` → `sapporo`), and other codas take the vowel
+/// they have in Japanese kana (`sikot` → `shikotsu`, `kusur` → `kusuru`).
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+///
+/// # Returns
+///
+/// * `String` - The Hepburn-style romanization of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_latn_to_hepburn;
+/// assert_eq!(convert_latn_to_hepburn("Satporo"), "Sapporo");
+/// assert_eq!(convert_latn_to_hepburn("Sikot"), "Shikotsu");
+/// assert_eq!(convert_latn_to_hepburn("kamuy cise"), "kamui chise");
+/// ```
+pub fn convert_latn_to_hepburn(latn: &str) -> String {
+    fn convert_syllable(remains: &str) -> &str {
+        match remains {
+            "si" => "shi",
+            "ci" => "chi",
+            "ca" => "cha",
+            "cu" => "chu",
+            "ce" => "che",
+            "co" => "cho",
+            "tu" => "tsu",
+            "hu" => "fu",
+            "yi" => "i",
+            "wi" => "ui",
+            "we" => "ue",
+            "wo" => "o",
+            _ => remains,
+        }
+    }
+
+    fn convert_word(word: &str) -> String {
+        let latn: String = remove_acute_accent(word)
+            .to_lowercase()
+            .chars()
+            .filter(|&c| c != '=' && !GLOTTAL_STOPS.contains(c) && !HIATUS_MARKS.contains(c))
+            .collect();
+        let syllables = separate(&latn);
+
+        let mut result = String::new();
+        for (i, syllable) in syllables.iter().enumerate() {
+            let last_char = match syllable.chars().last() {
+                Some(c) => c,
+                None => continue,
+            };
+            let (remains, coda) = if CONSONANTS.contains(last_char) {
+                syllable.split_at(syllable.len() - last_char.len_utf8())
+            } else {
+                (syllable.as_str(), "")
+            };
+            result.push_str(convert_syllable(remains));
+
+            let vowel = remains.chars().last().filter(|&c| VOWELS.contains(c));
+            let next = syllables
+                .get(i + 1)
+                .map(|next| convert_syllable(next))
+                .and_then(|next| next.chars().next())
+                .filter(|&c| !VOWELS.contains(c));
+
+            let converted_coda = match (coda, next) {
+                ("p" | "t" | "k", Some('c')) => "t".to_owned(),
+                ("p" | "t" | "k", Some(c)) if "ptks".contains(c) => c.to_string(),
+                ("k", _) => "ku".to_owned(),
+                ("p", _) => "pu".to_owned(),
+                ("t", _) => "tsu".to_owned(),
+                ("s", _) => "shi".to_owned(),
+                ("h", _) => "fu".to_owned(),
+                ("m", Some('p' | 'm')) => "m".to_owned(),
+                ("m", _) => "mu".to_owned(),
+                ("r", _) => format!("r{}", vowel.unwrap_or('u')),
+                ("w", _) => "u".to_owned(),
+                ("y", _) => "i".to_owned(),
+                (coda, _) => coda.to_owned(),
+            };
+            result.push_str(&converted_coda);
+        }
+
+        match_case(word, &result)
+    }
+
+    latn.split_into_words()
+        .into_iter()
+        .map(|word| {
+            if is_ainu_word(&word) {
+                convert_word(&word)
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+/// Convert Katakana to Japanese Hepburn-style practical romanization
+///
+/// The Katakana is read with [`convert_kana_to_latn`] and then spelled as described in
+/// [`convert_latn_to_hepburn`].
+///
+/// # Arguments
+///
+/// * `kana` - A string slice that holds the Katakana text.
+///
+/// # Returns
+///
+/// * `String` - The Hepburn-style romanization of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_kana_to_hepburn;
+/// assert_eq!(convert_kana_to_hepburn("シコッ"), "shikotsu");
+/// ```
+pub fn convert_kana_to_hepburn(kana: &str) -> String {
+    convert_latn_to_hepburn(&convert_kana_to_latn(kana))
+}
+
+/// Convert Japanese Hepburn-style romanization to romanized Ainu on a best-effort basis
+///
+/// Hepburn spellings are undone (`shi` → `si`, `chi` → `ci`, `tsu` → `tu`, `fu` → `hu`),
+/// voiced consonants are read as their voiceless Ainu counterparts, macrons are
+/// dropped, and `i` and `u` after a vowel are read as the glides `y` and `w` when no
+/// vowel follows. Vowels that Japanese added after a coda cannot be recognized and
+/// are kept.
+///
+/// # Arguments
+///
+/// * `hepburn` - A string slice that holds the Hepburn-style text.
+///
+/// # Returns
+///
+/// * `String` - The romanized Ainu reading of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_hepburn_to_latn;
+/// assert_eq!(convert_hepburn_to_latn("Shiretoko"), "Siretoko");
+/// assert_eq!(convert_hepburn_to_latn("Chitose"), "Citose");
+/// assert_eq!(convert_hepburn_to_latn("kamui"), "kamuy");
+/// ```
+pub fn convert_hepburn_to_latn(hepburn: &str) -> String {
+    fn convert_word(word: &str) -> String {
+        // Drop macrons and circumflexes of long vowels
+        let lower = word
+            .to_lowercase()
+            .nfd()
+            .filter(|&c| c != '\u{304}' && c != '\u{302}')
+            .collect::<String>();
+
+        let replaced = [
+            ("shi", "si"),
+            ("sh", "s"),
+            ("chi", "ci"),
+            ("ch", "c"),
+            ("tsu", "tu"),
+            ("ts", "t"),
+            ("fu", "hu"),
+            ("ji", "ci"),
+            ("j", "c"),
+            ("f", "h"),
+            ("g", "k"),
+            ("z", "s"),
+            ("d", "t"),
+            ("b", "p"),
+            ("v", "p"),
+        ]
+        .iter()
+        .fold(lower, |text, (from, to)| text.replace(from, to));
+
+        let chars: Vec<char> = replaced.chars().collect();
+        let result: String = chars
+            .iter()
+            .enumerate()
+            .map(|(i, &c)| {
+                let after_vowel = i > 0 && "aiueo".contains(chars[i - 1]);
+                let before_vowel = chars.get(i + 1).is_some_and(|c| "aiueo".contains(*c));
+                match c {
+                    'i' if after_vowel && !before_vowel => 'y',
+                    'u' if after_vowel && !before_vowel => 'w',
+                    _ => c,
+                }
+            })
+            .collect();
+
+        match_case(word, &result)
+    }
+
+    hepburn
+        .split_into_words()
+        .into_iter()
+        .map(|word| {
+            if is_ainu_word(&word) {
+                convert_word(&word)
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn latn_to_hepburn() {
+        assert_eq!(convert_latn_to_hepburn("tokapci"), "tokatchi");
+        assert_eq!(convert_latn_to_hepburn("kusur"), "kusuru");
+        assert_eq!(convert_latn_to_hepburn("sisam"), "shisamu");
+        assert_eq!(convert_latn_to_hepburn("ku=kor"), "kukoro");
+    }
+
+    #[test]
+    fn hepburn_to_latn() {
+        assert_eq!(convert_hepburn_to_latn("Tsurui"), "Turuy");
+        assert_eq!(convert_hepburn_to_latn("Ōdori"), "Otori");
+        assert_eq!(convert_hepburn_to_latn("Shakotan"), "Sakotan");
+    }
+}
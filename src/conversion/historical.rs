@@ -0,0 +1,167 @@
+use crate::conversion::cyrillic::convert_cyrl_to_latn;
+use std::ops::Range;
+
+/// Cyrillic spelling schemes that can be read into modern romanized Ainu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CyrillicScheme {
+    /// The modern Ainu Cyrillic script, as read by [`convert_cyrl_to_latn`].
+    #[default]
+    Modern,
+    /// Nineteenth-century Russian spellings such as Dobrotvorsky's dictionary (1875):
+    /// pre-reform letters (`ѣ`, `і`, `ѵ`, `ѳ`, final `ъ`), voiced letters for voiced
+    /// allophones (`б`, `д`, `г`, `з`, `ж`), and Russian letters with no Ainu
+    /// counterpart (`ч`, `ш`, `щ`, `ы`, `л`, `ф`).
+    Dobrotvorsky,
+}
+
+/// A letter of the source that has more than one plausible Ainu reading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ambiguity {
+    /// Byte range of the letter in the source text.
+    pub span: Range<usize>,
+    /// The letter as written in the source.
+    pub letter: char,
+    /// Possible romanized readings, the chosen one first.
+    pub readings: &'static [&'static str],
+}
+
+/// The result of reading a historical Cyrillic text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HistoricalReading {
+    /// The text in modern romanized Ainu.
+    pub latn: String,
+    /// Letters whose reading had to be guessed.
+    pub ambiguities: Vec<Ambiguity>,
+}
+
+/// Modern Cyrillic spelling of a Dobrotvorsky letter, with its alternative readings
+/// if the letter is ambiguous.
+fn modernize_dobrotvorsky(
+    letter: char,
+    after_consonant: bool,
+    word_final: bool,
+) -> Option<(&'static str, &'static [&'static str])> {
+    let modern: (&str, &[&str]) = match letter {
+        'ѣ' => ("э", &[]),
+        'і' | 'ѵ' => ("и", &[]),
+        'ї' => ("йи", &[]),
+        'ъ' if word_final => ("", &[]),
+        'б' => ("п", &[]),
+        'д' => ("т", &[]),
+        'г' => ("к", &[]),
+        'з' => ("с", &[]),
+        'ж' => ("ц", &["c", "s"]),
+        'ч' => ("ц", &[]),
+        'ш' | 'щ' => ("с", &[]),
+        'ы' => ("и", &["i", "u"]),
+        'л' => ("р", &[]),
+        'ф' | 'ѳ' => ("х", &["h", "p"]),
+        // Before the spelling reform, е after a consonant did not mark /y/
+        'е' if after_consonant => ("э", &[]),
+        'е' => ("е", &["ye", "e"]),
+        _ => return None,
+    };
+    Some(modern)
+}
+
+/// Read a Cyrillic text written in a historical scheme into modern romanized Ainu
+///
+/// # Arguments
+///
+/// * `cyrl` - A string slice that holds the Cyrillic text.
+/// * `scheme` - The spelling scheme of the text.
+///
+/// # Returns
+///
+/// * `HistoricalReading` - The romanized Ainu text, together with the letters whose
+///   reading is ambiguous.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_historical_cyrl_to_latn, CyrillicScheme};
+/// let reading = convert_historical_cyrl_to_latn("чисѣ", CyrillicScheme::Dobrotvorsky);
+/// assert_eq!(reading.latn, "cise");
+/// assert!(reading.ambiguities.is_empty());
+///
+/// let reading = convert_historical_cyrl_to_latn("кыръ", CyrillicScheme::Dobrotvorsky);
+/// assert_eq!(reading.latn, "kir");
+/// assert_eq!(reading.ambiguities[0].letter, 'ы');
+/// assert_eq!(reading.ambiguities[0].readings, ["i", "u"]);
+/// ```
+pub fn convert_historical_cyrl_to_latn(cyrl: &str, scheme: CyrillicScheme) -> HistoricalReading {
+    if scheme == CyrillicScheme::Modern {
+        return HistoricalReading {
+            latn: convert_cyrl_to_latn(cyrl),
+            ambiguities: Vec::new(),
+        };
+    }
+
+    let mut modern = String::new();
+    let mut ambiguities = Vec::new();
+    let mut chars = cyrl.char_indices().peekable();
+    let mut previous: Option<char> = None;
+
+    while let Some((index, current_char)) = chars.next() {
+        let current_lower = current_char.to_lowercase().next().unwrap();
+        let after_consonant = previous.is_some_and(|c| "бвгджзклмнпрстфхцчшщѳ".contains(c));
+        let word_final = !chars.peek().is_some_and(|(_, c)| c.is_alphabetic());
+        previous = Some(current_lower);
+
+        match modernize_dobrotvorsky(current_lower, after_consonant, word_final) {
+            Some((letter, readings)) => {
+                if current_char.is_uppercase() {
+                    modern.push_str(&letter.to_uppercase());
+                } else {
+                    modern.push_str(letter);
+                }
+                if !readings.is_empty() {
+                    ambiguities.push(Ambiguity {
+                        span: index..index + current_char.len_utf8(),
+                        letter: current_char,
+                        readings,
+                    });
+                }
+            }
+            None => modern.push(current_char),
+        }
+    }
+
+    HistoricalReading {
+        latn: convert_cyrl_to_latn(&modern),
+        ambiguities,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_dobrotvorsky() {
+        let reading = convert_historical_cyrl_to_latn("Бэтъ ежъ", CyrillicScheme::Dobrotvorsky);
+        assert_eq!(reading.latn, "Pet yec");
+        assert_eq!(
+            reading.ambiguities,
+            vec![
+                Ambiguity {
+                    span: 9..11,
+                    letter: 'е',
+                    readings: &["ye", "e"],
+                },
+                Ambiguity {
+                    span: 11..13,
+                    letter: 'ж',
+                    readings: &["c", "s"],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn modern_is_unchanged() {
+        let reading = convert_historical_cyrl_to_latn("айну", CyrillicScheme::Modern);
+        assert_eq!(reading.latn, "aynu");
+        assert!(reading.ambiguities.is_empty());
+    }
+}
@@ -0,0 +1,194 @@
+use crate::conversion::cyrillic::convert_cyrl_to_latn;
+use crate::conversion::katakana::convert_kana_to_latn;
+use crate::conversion::latin::CONSONANTS;
+use crate::syllable::separate;
+use crate::util::{is_ainu_word, remove_acute_accent, SplitIntoWords};
+use crate::Dialect;
+use unicode_normalization::UnicodeNormalization;
+
+/// Options for [`convert_latn_to_ipa_with`].
+#[derive(Debug, Clone, Default)]
+pub struct IpaOptions {
+    /// Dialect whose allophones are transcribed: `c` is [tʃ] in Hokkaido and [ts] in
+    /// Sakhalin, and a coda `r` is followed by an echo vowel in Hokkaido ([kaɾᵃ]).
+    pub dialect: Dialect,
+    /// Separate syllables with `.`.
+    pub syllable_boundaries: bool,
+}
+
+/// A syllable transcribed into phonemes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Syllable {
+    /// Whether the syllable carries the accent (an acute accent in Latin).
+    pub stressed: bool,
+    /// IPA symbols of the syllable.
+    pub segments: Vec<&'static str>,
+}
+
+/// Transcribe a romanized Ainu word into syllables of IPA symbols.
+pub(crate) fn transcribe_word(word: &str, dialect: Dialect) -> Vec<Syllable> {
+    let latn = word
+        .nfc()
+        .collect::<String>()
+        .to_lowercase()
+        .replace('=', "");
+
+    separate(&latn)
+        .iter()
+        .filter(|syllable| !syllable.is_empty())
+        .map(|syllable| {
+            let stressed = remove_acute_accent(syllable) != *syllable;
+            let syllable = remove_acute_accent(syllable);
+            let vowel = syllable.chars().find(|c| "aiueo".contains(*c));
+            let last_index = syllable.chars().count() - 1;
+
+            let mut segments = Vec::new();
+            for (i, c) in syllable.chars().enumerate() {
+                let is_coda = i == last_index && vowel.is_some() && CONSONANTS.contains(c);
+                let segment = match c {
+                    'a' => "a",
+                    'i' => "i",
+                    'u' => "u",
+                    'e' => "e",
+                    'o' => "o",
+                    'p' => "p",
+                    't' => "t",
+                    'k' => "k",
+                    'c' if dialect == Dialect::Sakhalin => "ts",
+                    'c' => "tʃ",
+                    's' => "s",
+                    'h' => "h",
+                    'm' => "m",
+                    'n' => "n",
+                    'r' => "ɾ",
+                    'w' => "w",
+                    'y' => "j",
+                    '’' => "ʔ",
+                    _ => continue,
+                };
+                segments.push(segment);
+
+                if c == 'r' && is_coda && dialect == Dialect::Hokkaido {
+                    segments.push(match vowel {
+                        Some('a') => "ᵃ",
+                        Some('i') => "ⁱ",
+                        Some('e') => "ᵉ",
+                        Some('o') => "ᵒ",
+                        _ => "ᵘ",
+                    });
+                }
+            }
+
+            Syllable { stressed, segments }
+        })
+        .collect()
+}
+
+/// Convert romanized Ainu to broad phonemic IPA
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+///
+/// # Returns
+///
+/// * `String` - The IPA transcription of the input string, without enclosing slashes.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_latn_to_ipa;
+/// assert_eq!(convert_latn_to_ipa("irankarapte"), "iɾankaɾapte");
+/// assert_eq!(convert_latn_to_ipa("cisé"), "tʃiˈse");
+/// assert_eq!(convert_latn_to_ipa("kor"), "koɾᵒ");
+/// ```
+pub fn convert_latn_to_ipa(latn: &str) -> String {
+    convert_latn_to_ipa_with(latn, &IpaOptions::default())
+}
+
+/// Convert romanized Ainu to broad phonemic IPA with the given options
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+/// * `options` - Transcription options, see [`IpaOptions`].
+///
+/// # Returns
+///
+/// * `String` - The IPA transcription of the input string, without enclosing slashes.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_latn_to_ipa_with, Dialect, IpaOptions};
+/// let options = IpaOptions {
+///     dialect: Dialect::Sakhalin,
+///     syllable_boundaries: true,
+/// };
+/// assert_eq!(convert_latn_to_ipa_with("cikír", &options), "tsi.ˈkiɾ");
+/// ```
+pub fn convert_latn_to_ipa_with(latn: &str, options: &IpaOptions) -> String {
+    latn.split_into_words()
+        .into_iter()
+        .map(|word| {
+            if !is_ainu_word(&word) {
+                return word;
+            }
+
+            transcribe_word(&word, options.dialect)
+                .iter()
+                .map(|syllable| {
+                    let stress = if syllable.stressed { "ˈ" } else { "" };
+                    format!("{}{}", stress, syllable.segments.concat())
+                })
+                .collect::<Vec<String>>()
+                .join(if options.syllable_boundaries { "." } else { "" })
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+/// Convert Katakana to broad phonemic IPA
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_kana_to_ipa;
+/// assert_eq!(convert_kana_to_ipa("チセ"), "tʃise");
+/// ```
+pub fn convert_kana_to_ipa(kana: &str) -> String {
+    convert_latn_to_ipa(&convert_kana_to_latn(kana))
+}
+
+/// Convert Cyrillic to broad phonemic IPA
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_cyrl_to_ipa;
+/// assert_eq!(convert_cyrl_to_ipa("айну"), "ajnu");
+/// ```
+pub fn convert_cyrl_to_ipa(cyrl: &str) -> String {
+    convert_latn_to_ipa(&convert_cyrl_to_latn(cyrl))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn transcribes_glottal_stop_and_accent() {
+        assert_eq!(convert_latn_to_ipa("a'e"), "aʔe");
+        assert_eq!(convert_latn_to_ipa("ku=kor kámuy"), "kukoɾᵒ ˈkamuj");
+        assert_eq!(convert_latn_to_ipa("ray."), "ɾaj.");
+    }
+
+    #[test]
+    fn sakhalin_coda_r_has_no_echo_vowel() {
+        let options = IpaOptions {
+            dialect: Dialect::Sakhalin,
+            ..Default::default()
+        };
+        assert_eq!(convert_latn_to_ipa_with("kor", &options), "koɾ");
+    }
+}
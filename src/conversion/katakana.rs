@@ -1,9 +1,388 @@
 // See notice in the TypeScript version https://github.com/mkpoli/ainconv/blob/main/src/conversion/katakana.ts
-use crate::conversion::latin::CONSONANTS;
-use crate::syllable::separate;
-use crate::util::{remove_acute_accent, IsLetter, SplitIntoWords};
+use crate::conversion::latin::{
+    GlottalStop, CONSONANTS, DEFAULT_HIATUS_MARKS, HIATUS_MARKS, VOWELS,
+};
+use crate::dictionary::Dictionary;
+use crate::language::identify_word;
+use crate::numeral::expand_numbers;
+use crate::syllable::{separate_with, SeparateOptions};
+use crate::util::{
+    capitalize, is_ainu_word, remove_acute_accent, to_katakana, IsLetter, SplitIntoWords,
+};
+use crate::{Error, Script};
 use unicode_normalization::UnicodeNormalization;
 
+/// How to read kana that have no native Ainu value, such as voiced kana (`ガ`,
+/// `ダ`, `ブ`, `ヅ`) and loanword digraphs (`ファ`, `ティ`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ForeignKana {
+    /// Read voiced kana as the Ainu voiceless phoneme (`ガ` → `ka`, `ブ` → `pu`).
+    /// `ジ` and `ヂ` are read as `ci`, since voiced [dʑ] is an allophone of Ainu *c*.
+    /// Loanword digraphs keep their ordinary Ainu reading (`ティ` → `tey`).
+    #[default]
+    Devoice,
+    /// Keep them as foreign segments romanized as in Japanese (`ガ` → `ga`,
+    /// `ファ` → `fa`, `ティ` → `ti`).
+    Keep,
+    /// Fail with [`Error::ForeignKana`] on voiced kana and loanword digraphs.
+    Error,
+}
+
+/// Options for [`convert_kana_to_latn_with`].
+#[derive(Debug, Clone, Default)]
+pub struct KanaToLatnOptions {
+    /// How to read voiced and loanword kana.
+    pub foreign_kana: ForeignKana,
+    /// Capitalize the first word of the text and every word after `。`, `！` or `？`.
+    pub capitalize_sentences: bool,
+    /// Words to capitalize wherever they occur, matched case-insensitively
+    /// against the romanized output (e.g. `"satporo"`).
+    pub proper_nouns: Vec<String>,
+    /// How to write the glottal stop inserted between a consonant and a following
+    /// vowel kana (`ㇰア` → `k’a`).
+    pub glottal_stop: GlottalStop,
+    /// Mark written between a vowel and a following `i` or `u` read as a separate
+    /// syllable (`アイヌ` → `a.inu`), so the hiatus reading stays visible. A `.` is
+    /// only read back as a hiatus mark with [`LatnToKanaOptions::dot_hiatus_mark`].
+    pub hiatus_mark: Option<char>,
+    /// Read the accent marks written by [`KanaAccent::Overline`] and
+    /// [`KanaAccent::Markup`] back as an acute accent (`チセ̅` → `cisé`). They are
+    /// dropped otherwise.
+    pub read_accent: bool,
+    /// Spell out numbers written with digits as Ainu numerals (`2` → `tu`).
+    pub expand_numbers: bool,
+    /// House spellings looked up before the rules, word by word (`アイヌ` → `aynu`).
+    pub dictionary: Dictionary,
+    /// Keep words that are not Ainu, such as Japanese glosses and Russian commentary,
+    /// as they are (see [`identify_word`](crate::identify_word)).
+    pub skip_non_ainu: bool,
+}
+
+/// Write the hiatus marks and glottal stops of a romanized word as the options ask.
+fn finish_latn(latn: &str, options: &KanaToLatnOptions) -> String {
+    let is_vowel = |c: char| c.to_lowercase().all(|c| VOWELS.contains(c));
+
+    let mut result = String::new();
+    let mut previous = None;
+    for c in latn.chars() {
+        if let Some(mark) = options.hiatus_mark {
+            if "iuIU".contains(c) && previous.is_some_and(is_vowel) {
+                result.push(mark);
+            }
+        }
+        result.push(c);
+        previous = Some(c);
+    }
+
+    options.glottal_stop.normalize(&result)
+}
+
+/// Restore capitalization of sentence-initial words and proper nouns in romanized text.
+fn restore_case(latn: &str, options: &KanaToLatnOptions) -> String {
+    let mut sentence_start = true;
+    let mut hiatus_marks = DEFAULT_HIATUS_MARKS.to_owned();
+    hiatus_marks.extend(options.hiatus_mark);
+
+    latn.split_into_words_with_hiatus(&hiatus_marks)
+        .into_iter()
+        .map(|word| {
+            if !is_ainu_word(&word) {
+                if word.contains(['.', '!', '?']) {
+                    sentence_start = true;
+                }
+                return word;
+            }
+
+            let is_proper_noun = options
+                .proper_nouns
+                .iter()
+                .any(|noun| noun.to_lowercase() == word.to_lowercase());
+            let capitalize_word =
+                is_proper_noun || (options.capitalize_sentences && sentence_start);
+            sentence_start = false;
+
+            if capitalize_word {
+                capitalize(&word)
+            } else {
+                word
+            }
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+/// Map a voiced kana to its voiceless Ainu counterpart, or `None` if it is not voiced.
+fn devoice(c: char) -> Option<char> {
+    match c {
+        'ジ' | 'ヂ' => return Some('チ'),
+        'じ' | 'ぢ' => return Some('ち'),
+        'ヴ' => return Some('プ'),
+        'ゔ' => return Some('ぷ'),
+        _ => {}
+    }
+
+    let mut decomposed = std::iter::once(c).nfd();
+    let base = decomposed.next()?;
+    if decomposed.next() != Some('\u{3099}') {
+        return None;
+    }
+
+    // The voiceless counterpart of b is p, not h
+    match base {
+        'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' | 'は' | 'ひ' | 'ふ' | 'へ' | 'ほ' => {
+            [base, '\u{309A}'].into_iter().nfc().next()
+        }
+        _ => Some(base),
+    }
+}
+
+/// Japanese romanization of a foreign kana (digraph), returning whether the next
+/// character was consumed.
+fn read_foreign_kana(current: char, next: Option<char>) -> Option<(&'static str, bool)> {
+    // Look hiragana up through the corresponding katakana
+    let current = to_katakana(current);
+    let next = next.map(to_katakana);
+
+    let digraph = match (current, next) {
+        ('フ', Some('ァ')) => Some("fa"),
+        ('フ', Some('ィ')) => Some("fi"),
+        ('フ', Some('ェ')) => Some("fe"),
+        ('フ', Some('ォ')) => Some("fo"),
+        ('テ', Some('ィ')) => Some("ti"),
+        ('デ', Some('ィ')) => Some("di"),
+        ('ド', Some('ゥ')) => Some("du"),
+        ('ギ', Some('ャ')) => Some("gya"),
+        ('ギ', Some('ュ')) => Some("gyu"),
+        ('ギ', Some('ョ')) => Some("gyo"),
+        ('ジ' | 'ヂ', Some('ャ')) => Some("ja"),
+        ('ジ' | 'ヂ', Some('ュ')) => Some("ju"),
+        ('ジ' | 'ヂ', Some('ェ')) => Some("je"),
+        ('ジ' | 'ヂ', Some('ョ')) => Some("jo"),
+        ('ビ', Some('ャ')) => Some("bya"),
+        ('ビ', Some('ュ')) => Some("byu"),
+        ('ビ', Some('ョ')) => Some("byo"),
+        ('ヴ', Some('ァ')) => Some("va"),
+        ('ヴ', Some('ィ')) => Some("vi"),
+        ('ヴ', Some('ェ')) => Some("ve"),
+        ('ヴ', Some('ォ')) => Some("vo"),
+        _ => None,
+    };
+    if let Some(digraph) = digraph {
+        return Some((digraph, true));
+    }
+
+    let single = match current {
+        'ガ' => "ga",
+        'ギ' => "gi",
+        'グ' => "gu",
+        'ゲ' => "ge",
+        'ゴ' => "go",
+        'ザ' => "za",
+        'ジ' => "ji",
+        'ズ' => "zu",
+        'ゼ' => "ze",
+        'ゾ' => "zo",
+        'ダ' => "da",
+        'ヂ' => "ji",
+        'ヅ' => "zu",
+        'デ' => "de",
+        'ド' => "do",
+        'バ' => "ba",
+        'ビ' => "bi",
+        'ブ' => "bu",
+        'ベ' => "be",
+        'ボ' => "bo",
+        'ヴ' => "vu",
+        _ => return None,
+    };
+    Some((single, false))
+}
+
+/// Spacing between words in Katakana output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum WordSpacing {
+    /// ASCII space (` `)
+    #[default]
+    Ascii,
+    /// Full-width space (`　`)
+    FullWidth,
+    /// No space between words
+    None,
+}
+
+/// Options for [`convert_latn_to_kana_with`].
+#[derive(Debug, Clone, Default)]
+pub struct LatnToKanaOptions {
+    /// Convert Western punctuation to Japanese punctuation (`.` → `。`, `,` → `、`,
+    /// `!` → `！`, `?` → `？`, `"…"` → `「…」`, `‘…’` or `'…'` → `『…』`), dropping the
+    /// spaces around it. A single quote is only read as a quote when it pairs with
+    /// another one around words; otherwise it is a glottal stop.
+    pub punctuation: bool,
+    /// Spacing between words.
+    pub word_spacing: WordSpacing,
+    /// Read `.` between two letters as a hiatus mark (`kay.e` → `カイエ`) rather than
+    /// as a full stop. `·` is always read as a hiatus mark.
+    pub dot_hiatus_mark: bool,
+    /// How to mark the accented syllable (`á` in Latin), which is dropped by default.
+    pub accent: KanaAccent,
+    /// Spell out numbers written with digits as Ainu numerals (`2` → `ト゚`).
+    pub expand_numbers: bool,
+    /// House spellings looked up before the rules, word by word.
+    pub dictionary: Dictionary,
+    /// Keep words that are not Ainu, such as Japanese glosses and Russian commentary,
+    /// as they are (see [`identify_word`](crate::identify_word)).
+    pub skip_non_ainu: bool,
+}
+
+/// How the accented syllable is marked in Katakana.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum KanaAccent {
+    /// The accent is dropped.
+    #[default]
+    None,
+    /// A combining overline (U+0305) follows the kana of the accented syllable (`チセ̅`).
+    Overline,
+    /// The kana of the accented syllable is wrapped in `<span class="accent">`.
+    Markup,
+}
+
+const ACCENT_MARKUP_OPEN: &str = "<span class=\"accent\">";
+const ACCENT_MARKUP_CLOSE: &str = "</span>";
+
+/// Replace the markup of [`KanaAccent::Markup`] with the overline of
+/// [`KanaAccent::Overline`].
+fn accent_markup_to_overline(kana: &str) -> String {
+    let mut result = String::new();
+    let mut rest = kana;
+    while let Some(start) = rest.find(ACCENT_MARKUP_OPEN) {
+        let inner = &rest[start + ACCENT_MARKUP_OPEN.len()..];
+        let Some(end) = inner.find(ACCENT_MARKUP_CLOSE) else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        result.push_str(&inner[..end]);
+        result.push('\u{305}');
+        rest = &inner[end + ACCENT_MARKUP_CLOSE.len()..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Put an acute accent on the last vowel of a romanized syllable.
+fn accent_last_vowel(syllable: &mut String) {
+    if let Some((i, c)) = syllable.char_indices().rfind(|(_, c)| "aiueo".contains(*c)) {
+        let accented: String = [c, '\u{301}'].into_iter().nfc().collect();
+        syllable.replace_range(i..i + c.len_utf8(), &accented);
+    }
+}
+
+/// Byte offsets of the single quotation marks used as quotes rather than as glottal
+/// stops: an opening `‘`, or `'` before a word, paired with the next `’` or `'` after
+/// a word. Unpaired marks are left to be read as glottal stops.
+fn single_quotes(latn: &str) -> Vec<usize> {
+    let chars: Vec<(usize, char)> = latn.char_indices().collect();
+    let is_letter = |i: Option<usize>| {
+        i.and_then(|i| chars.get(i))
+            .is_some_and(|(_, c)| c.is_ainu_letter())
+    };
+
+    let mut quotes = Vec::new();
+    let mut open = None;
+    for (i, &(offset, c)) in chars.iter().enumerate() {
+        let before_word = !is_letter(i.checked_sub(1)) && is_letter(Some(i + 1));
+        let after_word = i > 0 && !chars[i - 1].1.is_whitespace() && !is_letter(Some(i + 1));
+        match open {
+            None if c == '‘' || (c == '\'' && before_word) => open = Some(offset),
+            Some(start) if (c == '’' || c == '\'') && after_word => {
+                quotes.extend([start, offset]);
+                open = None;
+            }
+            _ => {}
+        }
+    }
+    quotes
+}
+
+/// Split romanized text into words and separators like [`SplitIntoWords`], making the
+/// single quotes found by [`single_quotes`] part of the separators. Each piece comes
+/// with whether it is a word.
+fn split_quoted_words(latn: &str, hiatus_marks: &str) -> Vec<(String, bool)> {
+    let mut pieces = Vec::new();
+    let mut start = 0;
+    for offset in single_quotes(latn) {
+        pieces.extend((&latn[start..offset]).split_into_words_with_hiatus(hiatus_marks));
+        let end = offset + latn[offset..].chars().next().map_or(0, char::len_utf8);
+        pieces.push(latn[offset..end].to_owned());
+        start = end;
+    }
+    pieces.extend((&latn[start..]).split_into_words_with_hiatus(hiatus_marks));
+
+    let mut words: Vec<(String, bool)> = Vec::new();
+    for piece in pieces {
+        let is_word = is_ainu_word(&piece) && !matches!(piece.as_str(), "'" | "‘" | "’");
+        match words.last_mut() {
+            Some((last, false)) if !is_word => last.push_str(&piece),
+            _ => words.push((piece, is_word)),
+        }
+    }
+    words
+}
+
+/// Convert the punctuation and spacing between two words, keeping track of whether a
+/// double quote and a single quote are open.
+fn convert_punctuation(
+    separator: &str,
+    options: &LatnToKanaOptions,
+    quote_open: &mut bool,
+    single_quote_open: &mut bool,
+) -> String {
+    let has_punctuation = separator.contains(['.', ',', '!', '?', '"', '“', '”', '\'', '‘', '’']);
+
+    if options.punctuation && has_punctuation {
+        return separator
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| match c {
+                '.' => '。',
+                ',' => '、',
+                '!' => '！',
+                '?' => '？',
+                '“' => '「',
+                '”' => '」',
+                '‘' => '『',
+                '’' => '』',
+                '\'' => {
+                    *single_quote_open = !*single_quote_open;
+                    if *single_quote_open {
+                        '『'
+                    } else {
+                        '』'
+                    }
+                }
+                '"' => {
+                    *quote_open = !*quote_open;
+                    if *quote_open {
+                        '「'
+                    } else {
+                        '」'
+                    }
+                }
+                _ => c,
+            })
+            .collect();
+    }
+
+    separator
+        .chars()
+        .map(|c| match (c, options.word_spacing) {
+            (' ', WordSpacing::Ascii) => " ".to_owned(),
+            (' ', WordSpacing::FullWidth) => "　".to_owned(),
+            (' ', WordSpacing::None) => String::new(),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
 /// Convert romanized Ainu to Katakana
 ///
 /// # Arguments
@@ -22,12 +401,43 @@ use unicode_normalization::UnicodeNormalization;
 /// assert_eq!(kana, "アイヌ");
 /// ```
 pub fn convert_latn_to_kana(latn: &str) -> String {
-    fn convert_word(word: &str) -> String {
+    convert_latn_to_kana_with(latn, &LatnToKanaOptions::default())
+}
+
+/// Convert romanized Ainu to Katakana with the given options
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu word.
+/// * `options` - Conversion options, see [`LatnToKanaOptions`].
+///
+/// # Returns
+///
+/// * `String` - The Katakana representation of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_latn_to_kana_with, LatnToKanaOptions, WordSpacing};
+/// let options = LatnToKanaOptions {
+///     punctuation: true,
+///     word_spacing: WordSpacing::FullWidth,
+///     ..Default::default()
+/// };
+/// let kana = convert_latn_to_kana_with("\"irankarapte\", aynu ne.", &options);
+/// assert_eq!(kana, "「イランカラㇷ゚テ」、アイヌ　ネ。");
+/// ```
+pub fn convert_latn_to_kana_with(latn: &str, options: &LatnToKanaOptions) -> String {
+    fn convert_word(word: &str, options: &LatnToKanaOptions) -> String {
         let latn = word.replace("=", "");
-        let latn = remove_acute_accent(&latn);
-        let latn = latn.to_ascii_lowercase();
+        let latn = latn.nfc().collect::<String>().to_lowercase();
 
-        let syllables = separate(&latn);
+        // Glottal stops are not written in Katakana
+        let separate_options = SeparateOptions {
+            glottal_stop: GlottalStop::Omitted,
+            dot_hiatus_mark: options.dot_hiatus_mark,
+        };
+        let syllables = separate_with(&latn, &separate_options);
 
         let mut result = String::new();
 
@@ -37,6 +447,9 @@ pub fn convert_latn_to_kana(latn: &str) -> String {
                 continue;
             }
 
+            let accented = remove_acute_accent(syllable) != *syllable;
+            let syllable = &remove_acute_accent(syllable);
+
             // V => (V, _)
             // VC => (V, C)
             // CVC => (CV, C)
@@ -126,7 +539,18 @@ pub fn convert_latn_to_kana(latn: &str) -> String {
                 "" => "",
                 _ => syllable,
             };
-            result.push_str(converted_remains);
+            match (accented, options.accent) {
+                (true, KanaAccent::Overline) => {
+                    result.push_str(converted_remains);
+                    result.push('\u{305}');
+                }
+                (true, KanaAccent::Markup) => {
+                    result.push_str(ACCENT_MARKUP_OPEN);
+                    result.push_str(converted_remains);
+                    result.push_str(ACCENT_MARKUP_CLOSE);
+                }
+                _ => result.push_str(converted_remains),
+            }
 
             let vowel = remains.chars().last();
 
@@ -181,13 +605,29 @@ pub fn convert_latn_to_kana(latn: &str) -> String {
             .replace("’", "")
     }
 
-    latn.split_into_words()
+    let latn = match options.expand_numbers {
+        true => expand_numbers(latn, Script::Latn),
+        false => latn.to_owned(),
+    };
+    let mut quote_open = false;
+    let mut single_quote_open = false;
+
+    let hiatus_marks = match options.dot_hiatus_mark {
+        true => HIATUS_MARKS,
+        false => DEFAULT_HIATUS_MARKS,
+    };
+
+    split_quoted_words(&latn, hiatus_marks)
         .into_iter()
-        .map(|word| {
-            if word.chars().all(|c| c.is_ainu_letter()) {
-                convert_word(&word)
+        .map(|(word, is_word)| {
+            if let Some(kana) = options.dictionary.lookup(&word, Script::Latn, Script::Kana) {
+                kana
+            } else if is_word && options.skip_non_ainu && !identify_word(&word).may_be_ainu() {
+                word
+            } else if is_word {
+                convert_word(&word, options)
             } else {
-                word.to_owned()
+                convert_punctuation(&word, options, &mut quote_open, &mut single_quote_open)
             }
         })
         .collect::<Vec<String>>()
@@ -212,7 +652,37 @@ pub fn convert_latn_to_kana(latn: &str) -> String {
 /// assert_eq!(latn, "ainu");
 /// ```
 pub fn convert_kana_to_latn(kana: &str) -> String {
-    fn convert_word(word: &str) -> String {
+    convert_kana_to_latn_with(kana, &KanaToLatnOptions::default())
+        .expect("default options never fail")
+}
+
+/// Convert Katakana to romanized Ainu with the given options
+///
+/// # Arguments
+///
+/// * `kana` - A string slice that holds the Katakana word.
+/// * `options` - Conversion options, see [`KanaToLatnOptions`].
+///
+/// # Returns
+///
+/// * `Result<String, Error>` - The romanized Ainu representation of the input string,
+///   or an error if a kana is rejected by the options.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_kana_to_latn_with, ForeignKana, KanaToLatnOptions};
+/// let mut options = KanaToLatnOptions::default();
+/// assert_eq!(convert_kana_to_latn_with("ガㇰ", &options).unwrap(), "kak");
+///
+/// options.foreign_kana = ForeignKana::Keep;
+/// assert_eq!(convert_kana_to_latn_with("ガㇰ", &options).unwrap(), "gak");
+///
+/// options.foreign_kana = ForeignKana::Error;
+/// assert!(convert_kana_to_latn_with("ガㇰ", &options).is_err());
+/// ```
+pub fn convert_kana_to_latn_with(kana: &str, options: &KanaToLatnOptions) -> Result<String, Error> {
+    fn convert_word(word: &str, options: &KanaToLatnOptions) -> Result<String, Error> {
         let mut result: Vec<String> = Vec::new();
         let mut chars = word
             .chars()
@@ -222,10 +692,44 @@ pub fn convert_kana_to_latn(kana: &str) -> String {
                 _ => c,
             })
             .nfc()
+            .map(|c| match options.foreign_kana {
+                ForeignKana::Devoice => devoice(c).unwrap_or(c),
+                _ => c,
+            })
             .peekable();
         while let Some(current_char) = chars.next() {
             let next_char = chars.peek();
 
+            if current_char == '\u{305}' {
+                if let Some(last) = result.last_mut().filter(|_| options.read_accent) {
+                    accent_last_vowel(last);
+                }
+                continue;
+            }
+
+            match options.foreign_kana {
+                ForeignKana::Keep => {
+                    if let Some((foreign, digraph)) =
+                        read_foreign_kana(current_char, next_char.copied())
+                    {
+                        result.push(foreign.to_owned());
+                        if digraph {
+                            chars.next();
+                        }
+                        continue;
+                    }
+                }
+                ForeignKana::Error => {
+                    if let Some((_, digraph)) = read_foreign_kana(current_char, next_char.copied())
+                    {
+                        let mut kana = current_char.to_string();
+                        kana.extend(next_char.filter(|_| digraph));
+                        return Err(Error::ForeignKana(kana));
+                    }
+                }
+                _ => {}
+            }
+
             let converted_digraph: Option<&str> = match (current_char, next_char) {
                 // ('ア', Some('イ')) => Some("ay"),
                 // ('ア', Some('ウ')) => Some("aw"),
@@ -239,7 +743,7 @@ pub fn convert_kana_to_latn(kana: &str) -> String {
                 // ('オ', Some('イ')) => Some("oy"),
                 // ('ウ', Some('イ')) => Some("uy"),
                 ('ㇷ', Some('゚')) => Some("p"),
-                ('ﾌ', Some('\u{ff9f}')) => Some("p"),
+                ('ﾌ', Some('\u{ff9f}' | '\u{309A}')) => Some("p"),
                 ('ト', Some('゚')) => Some("tu"),
                 ('チ', Some('ャ')) => Some("ca"),
                 ('チ', Some('ュ')) => Some("cu"),
@@ -277,6 +781,7 @@ pub fn convert_kana_to_latn(kana: &str) -> String {
                 'チ' => Some("ci"),
                 'テ' => Some("te"),
                 'ト' => Some("to"),
+                'ツ' => Some("tu"),
                 'ナ' => Some("na"),
                 'ニ' => Some("ni"),
                 'ヌ' => Some("nu"),
@@ -418,7 +923,7 @@ pub fn convert_kana_to_latn(kana: &str) -> String {
 
         let joined = result.join("’");
         fn is_vowel(c: char) -> bool {
-            matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+            VOWELS.contains(c)
         }
 
         // let joined = result.replace("'", "’");
@@ -438,16 +943,28 @@ pub fn convert_kana_to_latn(kana: &str) -> String {
             final_result.push(char);
         }
 
-        final_result.iter().collect()
+        Ok(finish_latn(
+            &final_result.iter().collect::<String>(),
+            options,
+        ))
     }
 
-    kana.split_into_words()
+    accent_markup_to_overline(kana)
+        .split_into_words()
         .into_iter()
         .map(|word| {
-            if word.chars().all(|c| c.is_ainu_letter()) {
-                convert_word(&word)
+            if let Some(latn) = options.dictionary.lookup(&word, Script::Kana, Script::Latn) {
+                Ok(finish_latn(&latn, options))
+            } else if is_ainu_word(&word)
+                && options.skip_non_ainu
+                && !identify_word(&word).may_be_ainu()
+            {
+                Ok(word)
+            } else if is_ainu_word(&word) {
+                convert_word(&word, options)
             } else {
-                word.to_owned()
+                Ok(word
+                    .to_owned()
                     .chars()
                     .map(|c| match c {
                         '。' => ". ".into(),
@@ -462,16 +979,20 @@ pub fn convert_kana_to_latn(kana: &str) -> String {
                         _ => c.to_string(),
                     })
                     .collect::<Vec<String>>()
-                    .join("")
+                    .join(""))
             }
         })
-        .collect::<Vec<String>>()
-        .join("")
+        .collect::<Result<Vec<String>, Error>>()
+        .map(|words| match options.expand_numbers {
+            true => expand_numbers(&words.join(""), Script::Latn),
+            false => words.join(""),
+        })
+        .map(|latn| restore_case(&latn, options))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::convert_latn_to_kana;
+    use super::*;
 
     #[test]
     fn glottal_stop_coda_does_not_panic() {
@@ -488,4 +1009,255 @@ mod tests {
         assert_eq!(convert_latn_to_kana("n"), "ン");
         assert_eq!(convert_latn_to_kana(""), "");
     }
+
+    #[test]
+    fn half_width_p() {
+        assert_eq!(convert_kana_to_latn("イランカラﾌﾟテ"), "irankarapte");
+    }
+
+    #[test]
+    fn foreign_kana() {
+        let devoice = KanaToLatnOptions::default();
+        let keep = KanaToLatnOptions {
+            foreign_kana: ForeignKana::Keep,
+            ..Default::default()
+        };
+        let error = KanaToLatnOptions {
+            foreign_kana: ForeignKana::Error,
+            ..Default::default()
+        };
+
+        assert_eq!(convert_kana_to_latn("ツ"), "tu");
+        assert_eq!(convert_kana_to_latn_with("ダブ", &devoice).unwrap(), "tapu");
+        assert_eq!(convert_kana_to_latn_with("ヂャ", &devoice).unwrap(), "ca");
+        assert_eq!(convert_kana_to_latn_with("ヅ", &devoice).unwrap(), "tu");
+        assert_eq!(convert_kana_to_latn_with("ティ", &devoice).unwrap(), "tey");
+        assert_eq!(convert_kana_to_latn_with("ダブ", &keep).unwrap(), "dabu");
+        assert_eq!(convert_kana_to_latn_with("ファ", &keep).unwrap(), "fa");
+        assert_eq!(convert_kana_to_latn_with("ティ", &keep).unwrap(), "ti");
+        assert_eq!(convert_kana_to_latn_with("ギャ", &keep).unwrap(), "gya");
+        assert_eq!(convert_kana_to_latn_with("ヂュ", &keep).unwrap(), "ju");
+        assert_eq!(convert_kana_to_latn_with("びょ", &keep).unwrap(), "byo");
+        assert_eq!(
+            convert_kana_to_latn_with("アヅ", &error),
+            Err(Error::ForeignKana("ヅ".to_owned()))
+        );
+        assert_eq!(
+            convert_kana_to_latn_with("ファ", &error),
+            Err(Error::ForeignKana("ファ".to_owned()))
+        );
+        assert_eq!(convert_kana_to_latn_with("ツ", &error).unwrap(), "tu");
+    }
+
+    #[test]
+    fn devoices_foreign_kana_by_default() {
+        // Voiced kana used to be passed through unconverted
+        assert_eq!(convert_kana_to_latn("ガッコウ"), "katkou");
+        assert_eq!(convert_kana_to_latn("ティ"), "tey");
+    }
+
+    #[test]
+    fn restores_case() {
+        let options = KanaToLatnOptions {
+            capitalize_sentences: true,
+            proper_nouns: vec!["satporo".to_owned()],
+            ..Default::default()
+        };
+
+        assert_eq!(
+            convert_kana_to_latn_with("イランカラㇷ゚テ。タパン　サッポロ　ネ！ソモ", &options)
+                .unwrap(),
+            "Irankarapte. Tapan Satporo ne! Somo"
+        );
+        assert_eq!(convert_kana_to_latn("アイヌ。ネ"), "ainu. ne");
+    }
+
+    #[test]
+    fn converts_punctuation() {
+        let options = LatnToKanaOptions {
+            punctuation: true,
+            word_spacing: WordSpacing::None,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            convert_latn_to_kana_with("irankarapte. \"tapan\" aynu ne!", &options),
+            "イランカラㇷ゚テ。「タパン」アイヌネ！"
+        );
+        assert_eq!(convert_latn_to_kana("tapan, aynu."), "タパン, アイヌ.");
+    }
+
+    #[test]
+    fn pairs_single_quotes() {
+        let options = LatnToKanaOptions {
+            punctuation: true,
+            ..Default::default()
+        };
+
+        assert_eq!(
+            convert_latn_to_kana_with("‘aynu’ ne.", &options),
+            "『アイヌ』ネ。"
+        );
+        assert_eq!(
+            convert_latn_to_kana_with("'aynu' ne.", &options),
+            "『アイヌ』ネ。"
+        );
+        assert_eq!(convert_latn_to_kana("'aynu' ne a'e"), "'アイヌ' ネ アエ");
+        assert_eq!(convert_latn_to_kana("ne’ ne’"), "ネ ネ");
+    }
+
+    #[test]
+    fn glottal_stop_policy() {
+        assert_eq!(convert_latn_to_kana("a'e"), "アエ");
+        assert_eq!(convert_latn_to_kana("a\u{2bc}e"), "アエ");
+        assert_eq!(convert_latn_to_kana("kamuy'"), "カムイ");
+        assert_eq!(convert_kana_to_latn("ㇰア"), "k\u{2019}a");
+
+        let options = KanaToLatnOptions {
+            glottal_stop: GlottalStop::ModifierLetter,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_kana_to_latn_with("ㇰア", &options).unwrap(),
+            "k\u{2bc}a"
+        );
+    }
+
+    #[test]
+    fn hiatus_mark() {
+        assert_eq!(convert_latn_to_kana("kay·e"), "カイエ");
+        assert_eq!(convert_latn_to_kana("kaye"), "カイェ");
+        assert_eq!(convert_latn_to_kana("a·i ne."), "アイ ネ.");
+        assert_eq!(convert_latn_to_kana("aynu.ne"), "アイヌ.ネ");
+
+        let options = LatnToKanaOptions {
+            dot_hiatus_mark: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_latn_to_kana_with("kay.e ne.", &options),
+            "カイエ ネ."
+        );
+
+        let options = KanaToLatnOptions {
+            hiatus_mark: Some('.'),
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_kana_to_latn_with("アイヌ カムイ", &options).unwrap(),
+            "a.inu kamu.i"
+        );
+        assert_eq!(convert_kana_to_latn_with("ウイ", &options).unwrap(), "uy");
+    }
+
+    #[test]
+    fn accent_round_trip() {
+        let mut options = LatnToKanaOptions {
+            accent: KanaAccent::Overline,
+            ..Default::default()
+        };
+        assert_eq!(convert_latn_to_kana_with("cisé", &options), "チセ\u{305}");
+        assert_eq!(
+            convert_latn_to_kana_with("kámuy", &options),
+            "カ\u{305}ムイ"
+        );
+
+        options.accent = KanaAccent::Markup;
+        let kana = convert_latn_to_kana_with("cisé", &options);
+        assert_eq!(kana, "チ<span class=\"accent\">セ</span>");
+
+        let options = KanaToLatnOptions {
+            read_accent: true,
+            ..Default::default()
+        };
+        assert_eq!(convert_kana_to_latn_with(&kana, &options).unwrap(), "cisé");
+        assert_eq!(
+            convert_kana_to_latn_with("カ\u{305}ムイ", &options).unwrap(),
+            "kámui"
+        );
+        assert_eq!(convert_kana_to_latn("チセ\u{305}"), "cise");
+    }
+
+    #[test]
+    fn expands_numbers() {
+        let options = LatnToKanaOptions {
+            expand_numbers: true,
+            ..Default::default()
+        };
+        assert_eq!(convert_latn_to_kana_with("2 cise", &options), "ト゚ チセ");
+
+        let options = KanaToLatnOptions {
+            expand_numbers: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_kana_to_latn_with("１１ チセ", &options).unwrap(),
+            "sine ikasma wan cise"
+        );
+
+        let options = KanaToLatnOptions {
+            expand_numbers: true,
+            capitalize_sentences: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_kana_to_latn_with("2 チセ。3 チセ", &options).unwrap(),
+            "Tu cise. Re cise"
+        );
+    }
+
+    #[test]
+    fn skips_non_ainu_words() {
+        let options = KanaToLatnOptions {
+            skip_non_ainu: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_kana_to_latn_with("ピリカ（美しい）", &options).unwrap(),
+            "pirika（美しい）"
+        );
+
+        let options = LatnToKanaOptions {
+            skip_non_ainu: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_latn_to_kana_with("pirka shinkansen", &options),
+            "ピㇼカ shinkansen"
+        );
+    }
+
+    #[test]
+    fn dictionary_overrides_rules() {
+        let dictionary = Dictionary::parse("aynu\tアイヌ").unwrap();
+        let options = LatnToKanaOptions {
+            dictionary: dictionary.clone(),
+            ..Default::default()
+        };
+        assert_eq!(convert_latn_to_kana_with("Aynu ne", &options), "アイヌ ネ");
+
+        let options = KanaToLatnOptions {
+            dictionary,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_kana_to_latn_with("アイヌ ネ", &options).unwrap(),
+            "aynu ne"
+        );
+        assert_eq!(
+            convert_kana_to_latn_with("あいぬ ね", &options).unwrap(),
+            "aynu ne"
+        );
+
+        let options = KanaToLatnOptions {
+            dictionary: Dictionary::parse("a'e\tアエ").unwrap(),
+            glottal_stop: GlottalStop::ModifierLetter,
+            hiatus_mark: Some('.'),
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_kana_to_latn_with("アエ アイヌ", &options).unwrap(),
+            "a\u{2bc}e a.inu"
+        );
+    }
 }
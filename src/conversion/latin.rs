@@ -1,2 +1,59 @@
 pub const VOWELS: &str = "aiueoáíúéó";
-pub const CONSONANTS: &str = "ptckmnshwry’";
\ No newline at end of file
+pub const CONSONANTS: &str = "ptckmnshwry’'ʼ";
+/// Characters read as the glottal stop in romanized Ainu.
+pub const GLOTTAL_STOPS: &str = "’'ʼ";
+/// Marks for an explicit syllable boundary inside a romanized word (`a.i`, `i·u`).
+pub const HIATUS_MARKS: &str = ".·";
+/// Hiatus marks read between two letters without being asked for; `.` is otherwise a
+/// full stop.
+pub const DEFAULT_HIATUS_MARKS: &str = "·";
+
+/// How the glottal stop is written in romanized Ainu.
+///
+/// Any of `’` (U+2019), `'` (U+0027) and `ʼ` (U+02BC) is accepted as a glottal stop
+/// when parsing; the policy decides which one is written in the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GlottalStop {
+    /// Right single quotation mark `’` (U+2019)
+    #[default]
+    RightQuotation,
+    /// ASCII apostrophe `'` (U+0027)
+    Apostrophe,
+    /// Modifier letter apostrophe `ʼ` (U+02BC)
+    ModifierLetter,
+    /// Not written at all
+    Omitted,
+}
+
+impl GlottalStop {
+    /// The string written for a glottal stop.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GlottalStop::RightQuotation => "’",
+            GlottalStop::Apostrophe => "'",
+            GlottalStop::ModifierLetter => "ʼ",
+            GlottalStop::Omitted => "",
+        }
+    }
+
+    /// Rewrite every glottal stop in `latn` according to this policy.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ainconv::GlottalStop;
+    /// assert_eq!(GlottalStop::ModifierLetter.normalize("a'e"), "aʼe");
+    /// assert_eq!(GlottalStop::Omitted.normalize("a’e"), "ae");
+    /// ```
+    pub fn normalize(&self, latn: &str) -> String {
+        latn.chars()
+            .map(|c| {
+                if GLOTTAL_STOPS.contains(c) {
+                    self.as_str().to_owned()
+                } else {
+                    c.to_string()
+                }
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,236 @@
+use crate::conversion::cyrillic::convert_cyrl_to_latn;
+use crate::conversion::ipa::{convert_latn_to_ipa, transcribe_word, IpaOptions};
+use crate::conversion::katakana::convert_kana_to_latn;
+use crate::detection::detect;
+use crate::util::{is_ainu_word, SplitIntoWords};
+use crate::Script;
+
+/// Phonetic alphabets for SSML `<phoneme>` tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PhonemeAlphabet {
+    /// International Phonetic Alphabet (`alphabet="ipa"`)
+    #[default]
+    Ipa,
+    /// X-SAMPA (`alphabet="x-sampa"`)
+    XSampa,
+}
+
+/// X-SAMPA symbol for an IPA symbol produced by [`transcribe_word`].
+fn ipa_to_xsampa(ipa: &str) -> &str {
+    match ipa {
+        "tʃ" => "tS",
+        "ɾ" => "4",
+        "ʔ" => "?",
+        // Echo vowels are not phonemic
+        "ᵃ" | "ⁱ" | "ᵘ" | "ᵉ" | "ᵒ" => "",
+        _ => ipa,
+    }
+}
+
+/// eSpeak NG phoneme mnemonic for an IPA symbol produced by [`transcribe_word`].
+fn ipa_to_espeak(ipa: &str) -> &str {
+    match ipa {
+        "tʃ" => "tS",
+        "ɾ" => "*",
+        "ʔ" => "?",
+        "ᵃ" | "ⁱ" | "ᵘ" | "ᵉ" | "ᵒ" => "",
+        _ => ipa,
+    }
+}
+
+/// Transcribe every Ainu word in `latn` syllable by syllable, keeping everything else.
+fn transcribe(
+    latn: &str,
+    options: &IpaOptions,
+    stress: &str,
+    boundary: &str,
+    symbol: fn(&str) -> &str,
+) -> String {
+    latn.split_into_words()
+        .into_iter()
+        .map(|word| {
+            if !is_ainu_word(&word) {
+                return word;
+            }
+
+            transcribe_word(&word, options.dialect)
+                .iter()
+                .map(|syllable| {
+                    let mut result = String::new();
+                    if syllable.stressed {
+                        result.push_str(stress);
+                    }
+                    for segment in syllable.segments.iter() {
+                        result.push_str(symbol(segment));
+                    }
+                    result
+                })
+                .collect::<Vec<String>>()
+                .join(boundary)
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+/// Convert romanized Ainu to X-SAMPA
+///
+/// Syllables are separated with `.` and the accented syllable is preceded by `"`.
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+///
+/// # Returns
+///
+/// * `String` - The X-SAMPA transcription of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_latn_to_xsampa;
+/// assert_eq!(convert_latn_to_xsampa("cisé"), "tSi.\"se");
+/// assert_eq!(convert_latn_to_xsampa("irankarapte"), "i.4an.ka.4ap.te");
+/// ```
+pub fn convert_latn_to_xsampa(latn: &str) -> String {
+    let options = IpaOptions {
+        syllable_boundaries: true,
+        ..Default::default()
+    };
+    convert_latn_to_xsampa_with(latn, &options)
+}
+
+/// Convert romanized Ainu to X-SAMPA with the given options
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+/// * `options` - Transcription options, see [`IpaOptions`].
+///
+/// # Returns
+///
+/// * `String` - The X-SAMPA transcription of the input string.
+pub fn convert_latn_to_xsampa_with(latn: &str, options: &IpaOptions) -> String {
+    let boundary = if options.syllable_boundaries { "." } else { "" };
+    transcribe(latn, options, "\"", boundary, ipa_to_xsampa)
+}
+
+/// Convert romanized Ainu to eSpeak NG phoneme mnemonics
+///
+/// The result can be passed to eSpeak NG inside `[[` and `]]`. The accented syllable
+/// is preceded by `'`; eSpeak NG has no syllable boundary mark, so syllables are
+/// written together.
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+///
+/// # Returns
+///
+/// * `String` - The eSpeak NG phoneme string of the input string.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::convert_latn_to_espeak;
+/// assert_eq!(convert_latn_to_espeak("kámuy"), "'kamuj");
+/// assert_eq!(convert_latn_to_espeak("a'e"), "a?e");
+/// ```
+pub fn convert_latn_to_espeak(latn: &str) -> String {
+    convert_latn_to_espeak_with(latn, &IpaOptions::default())
+}
+
+/// Convert romanized Ainu to eSpeak NG phoneme mnemonics with the given options
+///
+/// # Arguments
+///
+/// * `latn` - A string slice that holds the romanized Ainu text.
+/// * `options` - Transcription options, see [`IpaOptions`]. Syllable boundaries are
+///   never written.
+///
+/// # Returns
+///
+/// * `String` - The eSpeak NG phoneme string of the input string.
+pub fn convert_latn_to_espeak_with(latn: &str, options: &IpaOptions) -> String {
+    transcribe(latn, options, "'", "", ipa_to_espeak)
+}
+
+/// Escape the XML special characters of a text.
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// Wrap every Ainu word of a Latin, Katakana or Cyrillic text in an SSML `<phoneme>` tag
+///
+/// Each word is read in the script it is written in, and everything between the
+/// words is kept (XML-escaped).
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the Ainu text.
+/// * `alphabet` - The phonetic alphabet of the `ph` attribute.
+///
+/// # Returns
+///
+/// * `String` - The text with its words wrapped in `<phoneme>` tags.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{wrap_in_ssml_phoneme, PhonemeAlphabet};
+/// assert_eq!(
+///     wrap_in_ssml_phoneme("チセ ne", PhonemeAlphabet::XSampa),
+///     "<phoneme alphabet=\"x-sampa\" ph=\"tSi.se\">チセ</phoneme> \
+///      <phoneme alphabet=\"x-sampa\" ph=\"ne\">ne</phoneme>"
+/// );
+/// ```
+pub fn wrap_in_ssml_phoneme(text: &str, alphabet: PhonemeAlphabet) -> String {
+    text.split_into_words()
+        .into_iter()
+        .map(|word| {
+            if !is_ainu_word(&word) {
+                return escape_xml(&word);
+            }
+
+            let latn = match detect(&word) {
+                Script::Kana => convert_kana_to_latn(&word),
+                Script::Cyrl => convert_cyrl_to_latn(&word),
+                _ => word.clone(),
+            };
+            let (name, ph) = match alphabet {
+                PhonemeAlphabet::Ipa => ("ipa", convert_latn_to_ipa(&latn)),
+                PhonemeAlphabet::XSampa => ("x-sampa", convert_latn_to_xsampa(&latn)),
+            };
+
+            format!(
+                "<phoneme alphabet=\"{}\" ph=\"{}\">{}</phoneme>",
+                name,
+                escape_xml(&ph),
+                escape_xml(&word)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drops_echo_vowels() {
+        assert_eq!(convert_latn_to_xsampa("kor"), "ko4");
+        assert_eq!(convert_latn_to_espeak("kor"), "ko*");
+    }
+
+    #[test]
+    fn ssml_escapes_text() {
+        assert_eq!(
+            wrap_in_ssml_phoneme("айну & a", PhonemeAlphabet::Ipa),
+            "<phoneme alphabet=\"ipa\" ph=\"ajnu\">айну</phoneme> &amp; \
+             <phoneme alphabet=\"ipa\" ph=\"a\">a</phoneme>"
+        );
+    }
+}
@@ -1,5 +1,7 @@
-use crate::util::{is_cyrillic, is_katakana};
+use crate::util::{is_cyrillic, is_half_width_katakana, is_hiragana, is_katakana, is_latin};
 use crate::Script;
+use std::ops::Range;
+
 /// Detects the script type of a given Ainu language string.
 ///
 /// This function categorizes the script into one of several types based on the characters present in the string.
@@ -26,7 +28,7 @@ use crate::Script;
 /// assert_eq!(script, Script::Kana);
 /// ```
 pub fn detect(s: &str) -> Script {
-    let has_latin = s.chars().any(|c| c.is_alphabetic() && c.is_ascii());
+    let has_latin = s.chars().any(|c| c.is_alphabetic() && is_latin(c));
     let has_cyrillic = s.chars().any(|c| c.is_alphabetic() && is_cyrillic(c));
     let has_kana = s.chars().any(|c| c.is_alphabetic() && is_katakana(c));
     // let has_hangul = s.chars().any(|c| c.is_alphabetic() && is_hangul(c));
@@ -50,3 +52,210 @@ pub fn detect(s: &str) -> Script {
         Script::Unknown
     }
 }
+
+/// Scripts of individual letters, distinguishing the forms of kana.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LetterScript {
+    /// Latin letters, including accented letters such as `á`
+    Latin,
+    /// Full-width Katakana, including the small kana for Ainu (`ㇰ`, `ㇷ`…)
+    Katakana,
+    /// Half-width Katakana (`ｱ`, `ﾌ`…)
+    HalfWidthKatakana,
+    /// Hiragana
+    Hiragana,
+    /// Cyrillic letters
+    Cyrillic,
+}
+
+impl LetterScript {
+    /// The script of a letter, if it is written in one of the supported scripts.
+    pub fn of(c: char) -> Option<LetterScript> {
+        if !c.is_alphabetic() {
+            None
+        } else if is_latin(c) {
+            Some(LetterScript::Latin)
+        } else if is_katakana(c) {
+            Some(LetterScript::Katakana)
+        } else if is_half_width_katakana(c) {
+            Some(LetterScript::HalfWidthKatakana)
+        } else if is_hiragana(c) {
+            Some(LetterScript::Hiragana)
+        } else if is_cyrillic(c) {
+            Some(LetterScript::Cyrillic)
+        } else {
+            None
+        }
+    }
+
+    /// The writing system the letter belongs to; all forms of kana are [`Script::Kana`].
+    pub fn script(&self) -> Script {
+        match self {
+            LetterScript::Latin => Script::Latn,
+            LetterScript::Katakana | LetterScript::HalfWidthKatakana | LetterScript::Hiragana => {
+                Script::Kana
+            }
+            LetterScript::Cyrillic => Script::Cyrl,
+        }
+    }
+}
+
+/// The number of letters of one script in a text.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptCount {
+    /// The script of the letters.
+    pub script: LetterScript,
+    /// The number of letters.
+    pub count: usize,
+    /// The share of the letters of the text, from 0 to 1.
+    pub proportion: f64,
+}
+
+/// A stretch of text whose letters are all in one script.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptRun {
+    /// The script of the letters.
+    pub script: LetterScript,
+    /// Byte range of the run, from its first letter to its last letter or mark.
+    pub span: Range<usize>,
+}
+
+/// The result of [`detect_detailed`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DetailedDetection {
+    /// Letter counts of each script found, the most frequent first.
+    pub counts: Vec<ScriptCount>,
+    /// The script with the most letters, counting all forms of kana together, or
+    /// [`Script::Unknown`] if there are no letters.
+    pub dominant: Script,
+    /// The runs of letters of each script, in order.
+    pub runs: Vec<ScriptRun>,
+}
+
+/// Whether a character is a combining mark that belongs to the letter before it.
+fn is_mark(c: char) -> bool {
+    ('\u{0300}'..='\u{036F}').contains(&c)
+        || ('\u{3099}'..='\u{309C}').contains(&c)
+        || ('\u{FF9E}'..='\u{FF9F}').contains(&c)
+}
+
+/// Detects the scripts of a text letter by letter
+///
+/// Unlike [`detect`], which reports [`Script::Mixed`] for a single stray letter, this
+/// counts the letters of each script so that the caller can decide what proportion
+/// makes a text mixed. Hiragana and half-width Katakana are counted apart from
+/// Katakana. Spaces and punctuation do not end a run if the letters on both sides
+/// are in the same script.
+///
+/// # Arguments
+///
+/// * `s` - The text string to be analyzed.
+///
+/// # Returns
+///
+/// * `DetailedDetection` - Letter counts and proportions per script, the dominant
+///   script and the runs of each script.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{detect_detailed, LetterScript, Script};
+/// let detection = detect_detailed("kamuy ne. ｶﾑｲ");
+/// assert_eq!(detection.dominant, Script::Latn);
+/// assert_eq!(detection.counts[0].script, LetterScript::Latin);
+/// assert_eq!(detection.counts[0].count, 7);
+/// assert_eq!(detection.counts[1].script, LetterScript::HalfWidthKatakana);
+/// assert_eq!(detection.runs[0].span, 0..8);
+/// assert_eq!(detection.runs[1].span, 10..19);
+/// ```
+pub fn detect_detailed(s: &str) -> DetailedDetection {
+    let mut counts: Vec<ScriptCount> = Vec::new();
+    let mut runs: Vec<ScriptRun> = Vec::new();
+    let mut total = 0;
+
+    for (i, c) in s.char_indices() {
+        let end = i + c.len_utf8();
+        if is_mark(c) {
+            if let Some(run) = runs.last_mut().filter(|run| run.span.end == i) {
+                run.span.end = end;
+            }
+            continue;
+        }
+        let Some(script) = LetterScript::of(c) else {
+            continue;
+        };
+
+        total += 1;
+        match counts.iter_mut().find(|count| count.script == script) {
+            Some(count) => count.count += 1,
+            None => counts.push(ScriptCount {
+                script,
+                count: 1,
+                proportion: 0.0,
+            }),
+        }
+        match runs.last_mut().filter(|run| run.script == script) {
+            Some(run) => run.span.end = end,
+            None => runs.push(ScriptRun {
+                script,
+                span: i..end,
+            }),
+        }
+    }
+
+    for count in counts.iter_mut() {
+        count.proportion = count.count as f64 / total as f64;
+    }
+    counts.sort_by_key(|count| std::cmp::Reverse(count.count));
+
+    let dominant = [Script::Kana, Script::Latn, Script::Cyrl]
+        .into_iter()
+        .map(|script| {
+            let letters: usize = counts
+                .iter()
+                .filter(|count| count.script.script() == script)
+                .map(|count| count.count)
+                .sum();
+            (script, letters)
+        })
+        .filter(|(_, letters)| *letters > 0)
+        .fold(
+            None,
+            |best: Option<(Script, usize)>, (script, letters)| match best {
+                Some((_, most)) if most >= letters => best,
+                _ => Some((script, letters)),
+            },
+        )
+        .map_or(Script::Unknown, |(script, _)| script);
+
+    DetailedDetection {
+        counts,
+        dominant,
+        runs,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detects_accented_latin() {
+        assert_eq!(detect("á"), Script::Latn);
+        assert_eq!(detect("cisé"), Script::Latn);
+    }
+
+    #[test]
+    fn counts_each_script() {
+        let detection = detect_detailed("イランカラㇷ゚テ x");
+        assert_eq!(detection.dominant, Script::Kana);
+        assert_eq!(detection.counts.len(), 2);
+        assert!((detection.counts[0].proportion - 7.0 / 8.0).abs() < 1e-9);
+        // The handakuten belongs to the Katakana run
+        assert_eq!(detection.runs[0].span, 0..24);
+
+        let detection = detect_detailed("123");
+        assert_eq!(detection.dominant, Script::Unknown);
+        assert!(detection.runs.is_empty());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::util::{match_case, to_katakana};
+use crate::{Error, Script};
+use std::path::Path;
+use unicode_normalization::UnicodeNormalization;
+
+/// A word with its house spellings.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DictionaryEntry {
+    /// The romanized word (e.g. `aynu`).
+    pub latn: String,
+    /// The Katakana spelling (e.g. `アイヌ`).
+    pub kana: String,
+    /// The Cyrillic spelling, if it differs from the rules (e.g. `айну`).
+    pub cyrl: Option<String>,
+}
+
+impl DictionaryEntry {
+    fn spelling(&self, script: Script) -> Option<&str> {
+        match script {
+            Script::Latn => Some(&self.latn),
+            Script::Kana => Some(&self.kana),
+            Script::Cyrl => self.cyrl.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// Spellings that override the rule-based converters, word by word.
+///
+/// A dictionary can be loaded from a tab-separated file with one word per line:
+/// the Latin spelling, the Katakana spelling and optionally the Cyrillic spelling.
+/// Empty lines and lines starting with `#` are skipped, so that a file may start
+/// with a `# latn<TAB>kana<TAB>cyrl` header.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Dictionary {
+    entries: Vec<DictionaryEntry>,
+}
+
+impl Dictionary {
+    /// Create an empty dictionary.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the dictionary has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// The entries of the dictionary, in the order they were added.
+    pub fn entries(&self) -> &[DictionaryEntry] {
+        &self.entries
+    }
+
+    /// Add a word. A later entry for the same Latin spelling takes precedence.
+    pub fn insert(&mut self, latn: &str, kana: &str, cyrl: Option<&str>) {
+        let latn: String = latn.nfc().collect();
+        self.entries.retain(|entry| entry.latn != latn);
+        self.entries.push(DictionaryEntry {
+            latn,
+            kana: kana.nfc().collect(),
+            cyrl: cyrl.map(|cyrl| cyrl.nfc().collect()),
+        });
+    }
+
+    /// Parse a dictionary from tab-separated text
+    ///
+    /// # Arguments
+    ///
+    /// * `source` - The text of the dictionary, see [`Dictionary`] for the format.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Dictionary, Error>` - The dictionary, or
+    ///   [`Error::InvalidDictionaryLine`] with the number of the first line that does
+    ///   not have two or three non-empty fields.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use ainconv::{Dictionary, Script};
+    /// let dictionary = Dictionary::parse("# house spellings\naynu\tアイヌ\tайну\n").unwrap();
+    /// assert_eq!(
+    ///     dictionary.lookup("アイヌ", Script::Kana, Script::Latn),
+    ///     Some("aynu".to_owned())
+    /// );
+    /// assert!(Dictionary::parse("aynu").is_err());
+    /// ```
+    pub fn parse(source: &str) -> Result<Self, Error> {
+        let mut dictionary = Self::new();
+        for (i, line) in source.lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.split('\t').map(str::trim).collect();
+            match fields[..] {
+                [latn, kana] if !latn.is_empty() && !kana.is_empty() => {
+                    dictionary.insert(latn, kana, None)
+                }
+                [latn, kana, cyrl] if !latn.is_empty() && !kana.is_empty() => {
+                    dictionary.insert(latn, kana, Some(cyrl).filter(|cyrl| !cyrl.is_empty()))
+                }
+                _ => return Err(Error::InvalidDictionaryLine(i + 1)),
+            }
+        }
+        Ok(dictionary)
+    }
+
+    /// Load a dictionary from a tab-separated file
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file, see [`Dictionary`] for the format.
+    ///
+    /// # Returns
+    ///
+    /// * `Result<Dictionary, Error>` - The dictionary, [`Error::Io`] if the file cannot
+    ///   be read, or [`Error::InvalidDictionaryLine`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let source = std::fs::read_to_string(path).map_err(|error| Error::Io(error.to_string()))?;
+        Self::parse(&source)
+    }
+
+    /// Look up the spelling of a word in another script
+    ///
+    /// Words are matched ignoring case and the difference between hiragana and
+    /// Katakana, and a capitalized word gets a capitalized spelling.
+    ///
+    /// # Arguments
+    ///
+    /// * `word` - The word to look up.
+    /// * `from` - The script of the word.
+    /// * `to` - The script of the spelling.
+    ///
+    /// # Returns
+    ///
+    /// * `Option<String>` - The spelling, if the dictionary has one.
+    pub fn lookup(&self, word: &str, from: Script, to: Script) -> Option<String> {
+        let normalize = |s: &str| s.nfc().map(to_katakana).collect::<String>().to_lowercase();
+        let key = normalize(word);
+        self.entries
+            .iter()
+            .rev()
+            .find(|entry| entry.spelling(from).is_some_and(|s| normalize(s) == key))
+            .and_then(|entry| entry.spelling(to))
+            .map(|spelling| match_case(word, spelling))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_optional_cyrillic() {
+        let dictionary = Dictionary::parse("sisam\tシサㇺ\r\n\nAynu\tアイヌ\t\n").unwrap();
+        assert_eq!(dictionary.entries().len(), 2);
+        assert_eq!(dictionary.lookup("sisam", Script::Latn, Script::Cyrl), None);
+        assert_eq!(
+            dictionary.lookup("SISAM", Script::Latn, Script::Kana),
+            Some("シサㇺ".to_owned())
+        );
+        assert_eq!(
+            Dictionary::parse("a\tア\nb\n"),
+            Err(Error::InvalidDictionaryLine(2))
+        );
+    }
+
+    #[test]
+    fn matches_hiragana_with_katakana() {
+        let dictionary = Dictionary::parse("aynu\tアイヌ\n").unwrap();
+        assert_eq!(
+            dictionary.lookup("あいぬ", Script::Kana, Script::Latn),
+            Some("aynu".to_owned())
+        );
+    }
+}
@@ -0,0 +1,29 @@
+use std::fmt;
+
+/// Errors that can occur while converting Ainu text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A kana with no Ainu reading (e.g. voiced `ガ` or loanword `ファ`) was found
+    /// while [`ForeignKana::Error`](crate::ForeignKana::Error) was selected.
+    ForeignKana(String),
+    /// A number outside the range that Ainu numerals can spell (1 to 399).
+    NumberOutOfRange(u32),
+    /// A line of a [`Dictionary`](crate::Dictionary) file, numbered from 1, that does
+    /// not have two or three fields.
+    InvalidDictionaryLine(usize),
+    /// A file could not be read.
+    Io(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::ForeignKana(kana) => write!(f, "kana `{}` has no Ainu reading", kana),
+            Error::NumberOutOfRange(n) => write!(f, "{} cannot be spelled with Ainu numerals", n),
+            Error::InvalidDictionaryLine(line) => write!(f, "invalid dictionary line {}", line),
+            Error::Io(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
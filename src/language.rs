@@ -0,0 +1,230 @@
+use crate::conversion::cyrillic::{
+    convert_cyrl_to_latn, convert_cyrl_to_latn_with, CyrillicConvention, CyrlOptions,
+};
+use crate::conversion::katakana::convert_kana_to_latn;
+use crate::conversion::latin::{CONSONANTS, GLOTTAL_STOPS};
+use crate::detection::detect;
+use crate::syllable::separate;
+use crate::util::{is_ainu_word, is_hiragana, SplitIntoWords};
+use crate::validation::validate;
+use crate::Script;
+use std::ops::Range;
+use unicode_normalization::UnicodeNormalization;
+
+/// Languages told apart by [`identify_language`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    /// Ainu, in any script
+    Ainu,
+    /// Japanese, such as a gloss in kana or kanji
+    Japanese,
+    /// Russian, such as a commentary in Cyrillic
+    Russian,
+    /// Any other word, such as a non-Ainu word in Latin letters
+    Other,
+    /// A word that fits Ainu syllable structure but has nothing only Ainu spells that
+    /// way, such as `ne` or the loanword `piano`
+    Undetermined,
+}
+
+impl Language {
+    /// Whether a word of this language may be Ainu, that is, it is Ainu or
+    /// undetermined.
+    pub fn may_be_ainu(&self) -> bool {
+        matches!(self, Language::Ainu | Language::Undetermined)
+    }
+}
+
+/// A word of a text labelled with its language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageToken {
+    /// The word.
+    pub text: String,
+    /// Byte range of the word in the text.
+    pub span: Range<usize>,
+    /// The language of the word.
+    pub language: Language,
+}
+
+/// Russian letters that the Ainu Cyrillic alphabet does not use. `ь` is left out, as
+/// the Russian convention writes `ye` after a consonant as `ье`.
+const RUSSIAN_LETTERS: &str = "бгджзлфшщы";
+
+fn is_kanji(c: char) -> bool {
+    ('\u{4E00}'..='\u{9FFF}').contains(&c) || ('\u{3400}'..='\u{4DBF}').contains(&c)
+}
+
+/// Identify the language of a single word
+///
+/// Katakana with small Ainu kana (U+31F0–U+31FF) or `ト゚` is Ainu; a word with kanji,
+/// hiragana or the long vowel mark `ー` is Japanese; a Cyrillic word with letters the
+/// Ainu alphabet does not use (`б`, `л`, `ы`…) is Russian. Other words that follow
+/// Ainu syllable structure (see [`validate`](crate::validate)), reading voiced kana as
+/// voiceless and `ье` as in the Russian Cyrillic convention, are Ainu if they have a
+/// closed syllable, a glottal stop, `ъ` or `ь`, and [`Language::Undetermined`]
+/// otherwise.
+///
+/// # Arguments
+///
+/// * `word` - A string slice that holds the word.
+///
+/// # Returns
+///
+/// * `Language` - The most likely language of the word.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{identify_word, Language};
+/// assert_eq!(identify_word("イランカラㇷ゚テ"), Language::Ainu);
+/// assert_eq!(identify_word("こんにちは"), Language::Japanese);
+/// assert_eq!(identify_word("コーヒー"), Language::Japanese);
+/// assert_eq!(identify_word("слово"), Language::Russian);
+/// assert_eq!(identify_word("айну"), Language::Ainu);
+/// assert_eq!(identify_word("piano"), Language::Undetermined);
+/// ```
+pub fn identify_word(word: &str) -> Language {
+    let word = word.nfc().collect::<String>();
+    let has_ainu_kana =
+        word.contains("ト\u{309A}") || word.chars().any(|c| ('\u{31F0}'..='\u{31FF}').contains(&c));
+    if has_ainu_kana {
+        return Language::Ainu;
+    }
+    let has_japanese = word
+        .chars()
+        .any(|c| is_kanji(c) || is_hiragana(c) || c == 'ー');
+    if has_japanese {
+        return Language::Japanese;
+    }
+
+    let (readings, other) = match detect(&word) {
+        Script::Kana => (vec![convert_kana_to_latn(&word)], Language::Japanese),
+        Script::Cyrl => {
+            if word
+                .to_lowercase()
+                .chars()
+                .any(|c| RUSSIAN_LETTERS.contains(c))
+            {
+                return Language::Russian;
+            }
+            let mut readings = vec![convert_cyrl_to_latn(&word)];
+            // `ье` is `ye` after a consonant in the Russian convention
+            if word.to_lowercase().contains("ье") {
+                let russian = CyrlOptions {
+                    convention: CyrillicConvention::Russian,
+                    ..Default::default()
+                };
+                readings.push(convert_cyrl_to_latn_with(&word, &russian));
+            }
+            (readings, Language::Russian)
+        }
+        Script::Latn => (vec![word.clone()], Language::Other),
+        _ => return Language::Other,
+    };
+
+    let ainu_readings: Vec<&String> = readings
+        .iter()
+        .filter(|latn| validate(latn).is_empty())
+        .collect();
+    if ainu_readings.is_empty() {
+        return other;
+    }
+
+    // Open syllables alone are common to Japanese and to many loanwords
+    let has_ainu_spelling = word.to_lowercase().contains(['ъ', 'ь'])
+        || ainu_readings.iter().any(|latn| {
+            latn.contains(|c| GLOTTAL_STOPS.contains(c))
+                || separate(&latn.to_lowercase())
+                    .iter()
+                    .any(|syllable| syllable.ends_with(|c| CONSONANTS.contains(c)))
+        });
+    match has_ainu_spelling {
+        true => Language::Ainu,
+        false => Language::Undetermined,
+    }
+}
+
+/// Label every word of a text with its language
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the text.
+///
+/// # Returns
+///
+/// * `Vec<LanguageToken>` - The words of the text with their languages, in order.
+///   Spaces and punctuation are left out. An undetermined word next to an Ainu word
+///   is taken as Ainu.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{identify_language, Language};
+/// let tokens = identify_language("ピㇼカ（美しい）");
+/// assert_eq!(tokens[0].language, Language::Ainu);
+/// assert_eq!(tokens[1].language, Language::Japanese);
+/// assert_eq!(tokens[1].span, 12..21);
+/// ```
+pub fn identify_language(text: &str) -> Vec<LanguageToken> {
+    let mut tokens = Vec::new();
+    let mut offset = 0;
+    for word in text.split_into_words() {
+        let span = offset..offset + word.len();
+        offset = span.end;
+        if is_ainu_word(&word) {
+            tokens.push(LanguageToken {
+                language: identify_word(&word),
+                text: word,
+                span,
+            });
+        }
+    }
+
+    // An undetermined word is taken as Ainu next to Ainu words
+    let languages: Vec<Language> = tokens.iter().map(|token| token.language).collect();
+    let determined = |language: &&Language| **language != Language::Undetermined;
+    for (i, token) in tokens.iter_mut().enumerate() {
+        let before = languages[..i].iter().rev().find(determined);
+        let after = languages[i + 1..].iter().find(determined);
+        if token.language == Language::Undetermined
+            && (before == Some(&Language::Ainu) || after == Some(&Language::Ainu))
+        {
+            token.language = Language::Ainu;
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_embedded_commentary() {
+        let languages: Vec<Language> = identify_language("kamuy ne, русский комментарий")
+            .iter()
+            .map(|token| token.language)
+            .collect();
+        assert_eq!(
+            languages,
+            [
+                Language::Ainu,
+                Language::Ainu,
+                Language::Russian,
+                Language::Russian
+            ]
+        );
+        assert_eq!(identify_word("shinkansen"), Language::Other);
+        assert_eq!(identify_word("сирье"), Language::Ainu);
+        assert_eq!(identify_word("ガムイ"), Language::Undetermined);
+    }
+
+    #[test]
+    fn does_not_take_loanwords_as_ainu() {
+        for word in ["piano", "tomato", "テレビ", "ラジオ", "カメラ"] {
+            assert_eq!(identify_word(word), Language::Undetermined);
+        }
+        assert_eq!(identify_word("ne"), Language::Undetermined);
+        assert_eq!(identify_language("pirka piano")[1].language, Language::Ainu);
+    }
+}
@@ -1,5 +1,5 @@
 /// Writing systems supported by the library.
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Script {
     /// Katakana - カタカナ
     Kana,
@@ -15,23 +15,103 @@ pub enum Script {
     Unknown,
 }
 
+/// Dialect groups of the Ainu language.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Dialect {
+    /// Hokkaido dialects, such as Saru and Chitose
+    #[default]
+    Hokkaido,
+    /// Sakhalin dialects
+    Sakhalin,
+}
+
+mod error;
+pub use error::Error;
+
 mod util;
+use util::{is_ainu_word, SplitIntoWords};
 
 mod conversion {
+    pub mod braille;
     pub mod cyrillic;
     // pub mod hangul;
+    pub mod hepburn;
+    pub mod historical;
+    pub mod ipa;
     pub mod katakana;
     pub mod latin;
+    pub mod phoneme;
 }
 
 mod syllable;
-pub use syllable::separate;
+pub use syllable::{separate, separate_with, SeparateOptions};
 
 mod detection;
-pub use detection::detect;
+pub use detection::{
+    detect, detect_detailed, DetailedDetection, LetterScript, ScriptCount, ScriptRun,
+};
+
+mod dictionary;
+pub use dictionary::{Dictionary, DictionaryEntry};
+
+mod protect;
+pub use protect::{convert_escaped, convert_protected};
+
+mod language;
+pub use language::{identify_language, identify_word, Language, LanguageToken};
+
+mod orthography;
+pub use orthography::{detect_orthography, Orthography, OrthographyGuess};
+
+mod accent;
+pub use accent::{mark_accent, mark_accent_with, AccentOptions};
+
+mod sandhi;
+pub use sandhi::{surface_form, underlying_forms, SandhiChange, SandhiRule, SurfaceForm};
+
+mod validation;
+pub use validation::{validate, validate_with, PhonotacticIssue, PhonotacticIssueKind};
 
-pub use conversion::cyrillic::{convert_cyrl_to_latn, convert_latn_to_cyrl};
-pub use conversion::katakana::{convert_kana_to_latn, convert_latn_to_kana};
+mod lint;
+pub use lint::{lint, LintIssue, LintKind};
+
+mod numeral;
+pub use numeral::{expand_numbers, number_to_words};
+
+mod morphology;
+pub use morphology::{
+    conjugate, conjugate_to, possessive_form, possessive_form_with, Person, PossessiveOptions,
+    Transitivity,
+};
+
+pub use conversion::braille::{
+    convert_braille_to_kana, convert_braille_to_latn, convert_kana_to_braille,
+    convert_latn_to_braille,
+};
+pub use conversion::cyrillic::{
+    convert_cyrl_to_latn, convert_cyrl_to_latn_with, convert_latn_to_cyrl,
+    convert_latn_to_cyrl_practical, convert_latn_to_cyrl_with, CyrillicConvention, CyrlOptions,
+};
+pub use conversion::hepburn::{
+    convert_hepburn_to_latn, convert_kana_to_hepburn, convert_latn_to_hepburn,
+};
+pub use conversion::historical::{
+    convert_historical_cyrl_to_latn, Ambiguity, CyrillicScheme, HistoricalReading,
+};
+pub use conversion::ipa::{
+    convert_cyrl_to_ipa, convert_kana_to_ipa, convert_latn_to_ipa, convert_latn_to_ipa_with,
+    IpaOptions,
+};
+pub use conversion::katakana::{
+    convert_kana_to_latn, convert_kana_to_latn_with, convert_latn_to_kana,
+    convert_latn_to_kana_with, ForeignKana, KanaAccent, KanaToLatnOptions, LatnToKanaOptions,
+    WordSpacing,
+};
+pub use conversion::latin::GlottalStop;
+pub use conversion::phoneme::{
+    convert_latn_to_espeak, convert_latn_to_espeak_with, convert_latn_to_xsampa,
+    convert_latn_to_xsampa_with, wrap_in_ssml_phoneme, PhonemeAlphabet,
+};
 
 pub fn convert_cyrl_to_kana(cyrl: &str) -> String {
     convert_latn_to_kana(&convert_cyrl_to_latn(cyrl))
@@ -41,6 +121,162 @@ pub fn convert_kana_to_cyrl(kana: &str) -> String {
     convert_latn_to_cyrl(&convert_kana_to_latn(kana))
 }
 
+/// Convert Ainu text to the given script, whatever script it is written in
+///
+/// The script of the input is found with [`detect`]; text mixing scripts is converted
+/// word by word. Text in the target script is returned unchanged, and so is text of
+/// [`Script::Unknown`] or a target of [`Script::Mixed`] or [`Script::Unknown`].
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the Ainu text.
+/// * `script` - The script of the output.
+///
+/// # Returns
+///
+/// * `String` - The text written in the given script.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_to, Script};
+/// assert_eq!(convert_to("айну", Script::Kana), "アイヌ");
+/// assert_eq!(convert_to("aynu イタㇰ", Script::Kana), "アイヌ イタㇰ");
+/// ```
+pub fn convert_to(text: &str, script: Script) -> String {
+    if matches!(script, Script::Mixed | Script::Unknown) {
+        return text.to_owned();
+    }
+
+    match detect(text) {
+        Script::Mixed => text
+            .split_into_words()
+            .into_iter()
+            .map(|word| match detect(&word) {
+                // A word mixing scripts without a separator is converted run by run
+                Script::Mixed => {
+                    let starts: Vec<usize> = detect_detailed(&word)
+                        .runs
+                        .iter()
+                        .map(|run| run.span.start)
+                        .skip(1)
+                        .chain([word.len()])
+                        .collect();
+                    let mut start = 0;
+                    starts
+                        .into_iter()
+                        .map(|end| {
+                            let piece = &word[start..end];
+                            start = end;
+                            convert_from(piece, detect(piece), script)
+                        })
+                        .collect()
+                }
+                source => convert_from(&word, source, script),
+            })
+            .collect(),
+        source => convert_from(text, source, script),
+    }
+}
+
+/// Convert text written in `source` to `script`. Text in the target script, or of
+/// [`Script::Mixed`] or [`Script::Unknown`], is returned unchanged.
+fn convert_from(text: &str, source: Script, script: Script) -> String {
+    let latn = match source {
+        _ if source == script => return text.to_owned(),
+        Script::Kana => convert_kana_to_latn(text),
+        Script::Cyrl => convert_cyrl_to_latn(text),
+        Script::Latn => text.to_owned(),
+        Script::Mixed | Script::Unknown => return text.to_owned(),
+    };
+
+    match script {
+        Script::Kana => convert_latn_to_kana(&latn),
+        Script::Cyrl => convert_latn_to_cyrl(&latn),
+        _ => latn,
+    }
+}
+
+/// Options for [`convert_to_with`].
+#[derive(Debug, Clone, Default)]
+pub struct ConvertOptions {
+    /// Keep words that are not Ainu, such as Japanese glosses and Russian commentary,
+    /// as they are (see [`identify_word`]).
+    pub skip_non_ainu: bool,
+    /// Keep the text between `{{` and `}}` as it is, see [`convert_escaped`].
+    pub escapes: bool,
+}
+
+/// Convert Ainu text to the given script with the given options
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the Ainu text.
+/// * `script` - The script of the output.
+/// * `options` - Conversion options, see [`ConvertOptions`].
+///
+/// # Returns
+///
+/// * `String` - The text written in the given script.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_to_with, ConvertOptions, Script};
+/// let options = ConvertOptions {
+///     skip_non_ainu: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     convert_to_with("ピリカ（美しい）", Script::Latn, &options),
+///     "pirika（美しい）"
+/// );
+///
+/// let options = ConvertOptions {
+///     escapes: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     convert_to_with("{{kamuy}} kamuy", Script::Kana, &options),
+///     "kamuy カムイ"
+/// );
+/// ```
+pub fn convert_to_with(text: &str, script: Script, options: &ConvertOptions) -> String {
+    if options.escapes {
+        let options = ConvertOptions {
+            escapes: false,
+            ..options.clone()
+        };
+        return convert_escaped(text, |part| convert_to_with(part, script, &options));
+    }
+    if !options.skip_non_ainu {
+        return convert_to(text, script);
+    }
+
+    // Consecutive Ainu words are converted together with the spaces and punctuation
+    // between them; other words are kept with what follows them
+    let mut segments: Vec<(bool, String)> = Vec::new();
+    for word in text.split_into_words() {
+        let is_ainu = match is_ainu_word(&word) {
+            true => Some(identify_word(&word).may_be_ainu()),
+            false => None,
+        };
+        match (segments.last_mut(), is_ainu) {
+            (Some((ainu, segment)), Some(is_ainu)) if *ainu == is_ainu => segment.push_str(&word),
+            (Some((_, segment)), None) => segment.push_str(&word),
+            (_, is_ainu) => segments.push((is_ainu.unwrap_or(true), word)),
+        }
+    }
+
+    segments
+        .into_iter()
+        .map(|(ainu, segment)| match ainu {
+            true => convert_to(&segment, script),
+            false => segment,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,4 +289,32 @@ mod tests {
         assert_eq!(detect("Aynuイタㇰ"), Script::Mixed);
         assert_eq!(detect("愛努"), Script::Unknown);
     }
+
+    #[test]
+    fn cyrillic_skips_non_ainu_words() {
+        let options = CyrlOptions {
+            skip_non_ainu: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            convert_cyrl_to_latn_with("айну нэ, русский комментарий", &options),
+            "aynu ne, русский комментарий"
+        );
+    }
+
+    #[test]
+    fn reads_every_glottal_stop_spelling_alike() {
+        for latn in ["a'e", "a’e", "aʼe"] {
+            assert_eq!(convert_latn_to_cyrl(latn), "аъэ");
+            assert_eq!(convert_latn_to_kana(latn), "アエ");
+            assert_eq!(separate(latn), vec!["a", "’e"]);
+        }
+        assert_eq!(convert_cyrl_to_latn("аъэ"), "a’e");
+    }
+
+    #[test]
+    fn converts_word_mixing_scripts() {
+        assert_eq!(convert_to("Aynuイタㇰ", Script::Kana), "アイヌイタㇰ");
+        assert_eq!(convert_to("Aynuイタㇰ", Script::Latn), "Aynuitak");
+    }
 }
@@ -0,0 +1,263 @@
+use crate::conversion::cyrillic::convert_cyrl_to_latn;
+use crate::conversion::katakana::{convert_kana_to_latn, convert_latn_to_kana};
+use crate::conversion::latin::{GlottalStop, GLOTTAL_STOPS};
+use crate::detection::detect;
+use crate::util::{is_ainu_word, remove_acute_accent, SplitIntoWords};
+use crate::validation::{validate, PhonotacticIssueKind};
+use crate::Script;
+use std::fmt;
+use std::ops::Range;
+
+/// Kinds of issues found by [`lint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintKind {
+    /// A word that does not follow Ainu syllable structure, see [`validate`]
+    Phonotactic(PhonotacticIssueKind),
+    /// The same word spelled in different ways (`kamuy` and `kamui`)
+    InconsistentSpelling,
+    /// Glottal stops written with different characters (`'` and `’`)
+    MixedGlottalStops,
+    /// Small kana written both in full width and in half width (`ㇷ゚` and `ﾌﾟ`)
+    MixedKanaForms,
+}
+
+impl LintKind {
+    /// A short explanation of the issue.
+    pub fn description(&self) -> &'static str {
+        match self {
+            LintKind::Phonotactic(kind) => kind.description(),
+            LintKind::InconsistentSpelling => "inconsistent spelling",
+            LintKind::MixedGlottalStops => "mixed glottal stops",
+            LintKind::MixedKanaForms => "mixed full-width and half-width small kana",
+        }
+    }
+}
+
+/// An issue found by [`lint`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LintIssue {
+    /// What is wrong.
+    pub kind: LintKind,
+    /// Byte ranges in the document of every occurrence involved.
+    pub spans: Vec<Range<usize>>,
+    /// The distinct forms involved, in order of first occurrence.
+    pub variants: Vec<String>,
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {}",
+            self.kind.description(),
+            self.variants
+                .iter()
+                .map(|variant| format!("`{}`", variant))
+                .collect::<Vec<String>>()
+                .join(", ")
+        )
+    }
+}
+
+/// Collect the occurrences of each variant, keeping the order of first occurrence.
+#[derive(Default)]
+struct Variants {
+    variants: Vec<String>,
+    spans: Vec<Range<usize>>,
+}
+
+impl Variants {
+    fn add(&mut self, variant: String, span: Range<usize>) {
+        if !self.variants.contains(&variant) {
+            self.variants.push(variant);
+        }
+        self.spans.push(span);
+    }
+
+    fn into_issue(self, kind: LintKind) -> Option<LintIssue> {
+        (self.variants.len() > 1).then_some(LintIssue {
+            kind,
+            spans: self.spans,
+            variants: self.variants,
+        })
+    }
+}
+
+/// Half-width kana used for small kana, and the full-width small kana.
+const HALF_WIDTH_KANA: [(char, char); 26] = [
+    ('ｱ', 'ァ'),
+    ('ｲ', 'ィ'),
+    ('ｳ', 'ゥ'),
+    ('ｴ', 'ェ'),
+    ('ｵ', 'ォ'),
+    ('ｸ', 'ㇰ'),
+    ('ｼ', 'ㇱ'),
+    ('ﾂ', 'ッ'),
+    ('ﾄ', 'ㇳ'),
+    ('ﾇ', 'ㇴ'),
+    ('ﾊ', 'ㇵ'),
+    ('ﾋ', 'ㇶ'),
+    ('ﾌ', 'ㇷ'),
+    ('ﾍ', 'ㇸ'),
+    ('ﾎ', 'ㇹ'),
+    ('ﾑ', 'ㇺ'),
+    ('ﾗ', 'ㇻ'),
+    ('ﾘ', 'ㇼ'),
+    ('ﾙ', 'ㇽ'),
+    ('ﾚ', 'ㇾ'),
+    ('ﾛ', 'ㇿ'),
+    ('ﾝ', 'ン'),
+    ('ﾞ', '\u{3099}'),
+    ('ﾟ', '\u{309A}'),
+    ('゛', '\u{3099}'),
+    ('゜', '\u{309A}'),
+];
+
+/// The spelling of a word, leaving out the character variation that [`lint`] reports
+/// for the whole document.
+fn spelling(word: &str) -> String {
+    let word: String = word
+        .chars()
+        .map(|c| {
+            HALF_WIDTH_KANA
+                .iter()
+                .find(|(half, _)| *half == c)
+                .map_or(c, |(_, full)| *full)
+        })
+        .collect();
+    GlottalStop::RightQuotation.normalize(&remove_acute_accent(&word).to_lowercase())
+}
+
+/// The spelling shared by all variants of a word: its romanized reading after a
+/// round trip through Katakana, unaccented and in lower case.
+fn normalize(word: &str, script: Script) -> String {
+    let latn = match script {
+        Script::Kana => convert_kana_to_latn(word),
+        Script::Cyrl => convert_cyrl_to_latn(word),
+        _ => word.to_owned(),
+    };
+    let latn = remove_acute_accent(&latn.replace('=', "")).to_lowercase();
+    convert_kana_to_latn(&convert_latn_to_kana(&latn))
+}
+
+/// Find inconsistent spellings and phonotactic issues in an Ainu document
+///
+/// Every word is checked with [`validate`]. Words are then clustered by their reading
+/// (`kamuy`, `kamui` and `Kamuy` are all read as `kamui`), and a cluster with more
+/// than one spelling in the same script is reported. Glottal stops written with
+/// different characters, and small kana written both in full width and in half
+/// width, are reported once for the whole document.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the document, in any script.
+///
+/// # Returns
+///
+/// * `Vec<LintIssue>` - The issues found: phonotactic issues in document order, then
+///   inconsistent spellings, then mixed characters.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{lint, LintKind};
+/// let issues = lint("kamuy ne. Kamui ne.");
+/// assert_eq!(issues.len(), 1);
+/// assert_eq!(issues[0].kind, LintKind::InconsistentSpelling);
+/// assert_eq!(issues[0].variants, vec!["kamuy", "kamui"]);
+/// assert_eq!(issues[0].spans, vec![0..5, 10..15]);
+///
+/// assert_eq!(lint("a'e a’e")[0].kind, LintKind::MixedGlottalStops);
+/// ```
+pub fn lint(text: &str) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    // Clusters of spellings keyed by script and reading
+    let mut spellings: Vec<((Script, String), Variants)> = Vec::new();
+    let mut glottal_stops = Variants::default();
+    let mut small_kana = Variants::default();
+
+    let mut offset = 0;
+    for word in text.split_into_words() {
+        let start = offset;
+        offset += word.len();
+        // A lone quotation mark is read as a word made of glottal stops
+        if !is_ainu_word(&word) || word.chars().all(|c| GLOTTAL_STOPS.contains(c)) {
+            continue;
+        }
+        let script = detect(&word);
+
+        for issue in validate(&word) {
+            let span = match script {
+                Script::Latn => start + issue.span.start..start + issue.span.end,
+                _ => start..offset,
+            };
+            issues.push(LintIssue {
+                kind: LintKind::Phonotactic(issue.kind),
+                spans: vec![span],
+                variants: vec![issue.text],
+            });
+        }
+
+        let key = (script, normalize(&word, script));
+        match spellings.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, variants)) => variants.add(spelling(&word), start..offset),
+            None => {
+                let mut variants = Variants::default();
+                variants.add(spelling(&word), start..offset);
+                spellings.push((key, variants));
+            }
+        }
+
+        let last = word.chars().count() - 1;
+        for (i, (index, c)) in word.char_indices().enumerate() {
+            let span = start + index..start + index + c.len_utf8();
+            if GLOTTAL_STOPS.contains(c) && i > 0 && i < last {
+                glottal_stops.add(c.to_string(), span);
+            } else if ('\u{31F0}'..='\u{31FF}').contains(&c) {
+                small_kana.add("full-width".to_owned(), span);
+            } else if ('\u{FF67}'..='\u{FF9F}').contains(&c) {
+                small_kana.add("half-width".to_owned(), span);
+            }
+        }
+    }
+
+    issues.extend(
+        spellings
+            .into_iter()
+            .filter_map(|(_, variants)| variants.into_issue(LintKind::InconsistentSpelling)),
+    );
+    issues.extend(glottal_stops.into_issue(LintKind::MixedGlottalStops));
+    issues.extend(small_kana.into_issue(LintKind::MixedKanaForms));
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_mixed_kana_forms() {
+        let issues = lint("イランカラㇷ゚テ イランカラﾌﾟテ");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, LintKind::MixedKanaForms);
+        assert_eq!(issues[0].variants, vec!["full-width", "half-width"]);
+        assert_eq!(issues[0].spans, vec![15..18, 40..43, 43..46]);
+    }
+
+    #[test]
+    fn reports_phonotactic_issues_in_document() {
+        let issues = lint("kamuy tiac");
+        assert_eq!(
+            issues[0].kind,
+            LintKind::Phonotactic(PhonotacticIssueKind::ImpossibleSyllable)
+        );
+        assert_eq!(issues[0].spans, vec![6..8]);
+        assert!(lint("irankarapte. Irankarapte!").is_empty());
+    }
+
+    #[test]
+    fn skips_lone_quotation_marks() {
+        assert!(lint("kamuy ' '").is_empty());
+        assert!(lint("kamuy ’ ʼ").is_empty());
+    }
+}
@@ -0,0 +1,258 @@
+use crate::conversion::latin::{CONSONANTS, VOWELS};
+use crate::sandhi::surface_form;
+use crate::syllable::separate;
+use crate::util::remove_acute_accent;
+use crate::{convert_to, Script};
+use std::collections::HashMap;
+
+/// Grammatical persons marked by the personal affixes of a verb.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Person {
+    /// First person singular (`ku=`)
+    FirstSingular,
+    /// Second person singular (`e=`)
+    SecondSingular,
+    /// Third person, which has no affix
+    Third,
+    /// First person plural exclusive (`=as`, `ci=`)
+    FirstPluralExclusive,
+    /// First person plural inclusive (`=an`, `a=`)
+    FirstPluralInclusive,
+    /// Second person plural (`eci=`)
+    SecondPlural,
+    /// Indefinite ("fourth") person, which shares the inclusive affixes (`=an`, `a=`)
+    Indefinite,
+}
+
+/// Transitivity of a verb, which selects the subject affixes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Transitivity {
+    /// A verb without an object, such as `arpa` "to go"
+    Intransitive,
+    /// A verb with an object, such as `kor` "to have"
+    Transitive,
+}
+
+/// The subject prefix or suffix of a verb, written with its `=`.
+fn subject_affix(person: Person, transitivity: Transitivity) -> Option<&'static str> {
+    match (person, transitivity) {
+        (Person::FirstSingular, _) => Some("ku="),
+        (Person::SecondSingular, _) => Some("e="),
+        (Person::Third, _) => None,
+        (Person::FirstPluralExclusive, Transitivity::Intransitive) => Some("=as"),
+        (Person::FirstPluralExclusive, Transitivity::Transitive) => Some("ci="),
+        (Person::FirstPluralInclusive | Person::Indefinite, Transitivity::Intransitive) => {
+            Some("=an")
+        }
+        (Person::FirstPluralInclusive | Person::Indefinite, Transitivity::Transitive) => Some("a="),
+        (Person::SecondPlural, _) => Some("eci="),
+    }
+}
+
+/// Conjugate an Ainu verb for the person of its subject
+///
+/// The Hokkaido (Saru) personal affixes are attached with a `=`, and the sound
+/// changes at the join are applied with [`surface_form`](crate::surface_form).
+///
+/// # Arguments
+///
+/// * `verb` - The romanized verb stem, without personal affixes.
+/// * `person` - The person of the subject.
+/// * `transitivity` - Whether the verb takes an object.
+///
+/// # Returns
+///
+/// * `String` - The romanized verb with its personal affix.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{conjugate, Person, Transitivity};
+/// assert_eq!(
+///     conjugate("kor", Person::FirstSingular, Transitivity::Transitive),
+///     "ku=kor"
+/// );
+/// assert_eq!(
+///     conjugate("arpa", Person::FirstPluralExclusive, Transitivity::Intransitive),
+///     "arpa=as"
+/// );
+/// assert_eq!(
+///     conjugate("kor", Person::Indefinite, Transitivity::Transitive),
+///     "a=kor"
+/// );
+/// ```
+pub fn conjugate(verb: &str, person: Person, transitivity: Transitivity) -> String {
+    match subject_affix(person, transitivity) {
+        Some(affix) if affix.starts_with('=') => surface_form(&[verb, affix]).surface,
+        Some(affix) => surface_form(&[affix, verb]).surface,
+        None => verb.to_owned(),
+    }
+}
+
+/// Conjugate an Ainu verb and write it in the given script
+///
+/// # Arguments
+///
+/// * `verb` - The romanized verb stem, without personal affixes.
+/// * `person` - The person of the subject.
+/// * `transitivity` - Whether the verb takes an object.
+/// * `script` - The script of the output.
+///
+/// # Returns
+///
+/// * `String` - The conjugated verb in the given script.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{conjugate_to, Person, Script, Transitivity};
+/// assert_eq!(
+///     conjugate_to("kor", Person::FirstSingular, Transitivity::Transitive, Script::Kana),
+///     "クコㇿ"
+/// );
+/// ```
+pub fn conjugate_to(
+    verb: &str,
+    person: Person,
+    transitivity: Transitivity,
+    script: Script,
+) -> String {
+    convert_to(&conjugate(verb, person, transitivity), script)
+}
+
+/// Options for [`possessive_form_with`].
+#[derive(Debug, Clone)]
+pub struct PossessiveOptions {
+    /// Nouns whose possessive form does not follow the rule, keyed by the noun in
+    /// lower case (e.g. `"cise"` → `"cisehe"`).
+    pub irregular: HashMap<String, String>,
+    /// Script of the output.
+    pub script: Script,
+}
+
+impl Default for PossessiveOptions {
+    fn default() -> Self {
+        Self {
+            irregular: HashMap::new(),
+            script: Script::Latn,
+        }
+    }
+}
+
+/// Build the possessive (belonging) form of an Ainu noun
+///
+/// A noun ending in an open syllable takes `-hV`, and one ending in a closed syllable
+/// takes `-V`, where `V` is the vowel of the last syllable (`sapa` → `sapaha`, `tek` →
+/// `teke`).
+///
+/// # Arguments
+///
+/// * `noun` - The romanized noun.
+///
+/// # Returns
+///
+/// * `String` - The romanized possessive form.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::possessive_form;
+/// assert_eq!(possessive_form("sapa"), "sapaha");
+/// assert_eq!(possessive_form("kema"), "kemaha");
+/// assert_eq!(possessive_form("tek"), "teke");
+/// ```
+pub fn possessive_form(noun: &str) -> String {
+    possessive_form_with(noun, &PossessiveOptions::default())
+}
+
+/// Build the possessive (belonging) form of an Ainu noun with the given options
+///
+/// # Arguments
+///
+/// * `noun` - The romanized noun.
+/// * `options` - Irregular forms and the output script, see [`PossessiveOptions`].
+///
+/// # Returns
+///
+/// * `String` - The possessive form in the given script.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{possessive_form_with, PossessiveOptions, Script};
+/// let mut options = PossessiveOptions {
+///     script: Script::Kana,
+///     ..Default::default()
+/// };
+/// assert_eq!(possessive_form_with("sapa", &options), "サパハ");
+///
+/// options.script = Script::Latn;
+/// options.irregular.insert("cise".to_owned(), "cisehe".to_owned());
+/// assert_eq!(possessive_form_with("cise", &options), "cisehe");
+/// ```
+pub fn possessive_form_with(noun: &str, options: &PossessiveOptions) -> String {
+    let lower = remove_acute_accent(noun).to_lowercase();
+    let latn = match options.irregular.get(&lower) {
+        Some(irregular) => irregular.clone(),
+        None => {
+            let last = separate(&lower).pop().unwrap_or_default();
+            match last.chars().rfind(|c| VOWELS.contains(*c)) {
+                Some(vowel) if last.ends_with(|c| CONSONANTS.contains(c)) => {
+                    format!("{}{}", noun, vowel)
+                }
+                Some(vowel) => format!("{}h{}", noun, vowel),
+                None => noun.to_owned(),
+            }
+        }
+    };
+    convert_to(&latn, options.script)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intransitive_paradigm() {
+        let forms: Vec<String> = [
+            Person::FirstSingular,
+            Person::SecondSingular,
+            Person::Third,
+            Person::FirstPluralExclusive,
+            Person::FirstPluralInclusive,
+            Person::SecondPlural,
+        ]
+        .iter()
+        .map(|&person| conjugate("arpa", person, Transitivity::Intransitive))
+        .collect();
+        assert_eq!(
+            forms,
+            ["ku=arpa", "e=arpa", "arpa", "arpa=as", "arpa=an", "eci=arpa"]
+        );
+    }
+
+    #[test]
+    fn renders_in_every_script() {
+        let transitive = Transitivity::Transitive;
+        assert_eq!(
+            conjugate_to("nukar", Person::SecondPlural, transitive, Script::Cyrl),
+            "эци=нукар"
+        );
+        assert_eq!(
+            conjugate_to(
+                "kor",
+                Person::FirstPluralExclusive,
+                transitive,
+                Script::Latn
+            ),
+            "ci=kor"
+        );
+    }
+
+    #[test]
+    fn possessive_of_closed_syllable() {
+        assert_eq!(possessive_form("kisar"), "kisara");
+        assert_eq!(possessive_form("sík"), "síki");
+        assert_eq!(possessive_form("k"), "k");
+    }
+}
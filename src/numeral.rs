@@ -0,0 +1,169 @@
+use crate::{convert_to, Error, Script};
+
+/// Numerals from one to nine.
+const UNITS: [&str; 9] = [
+    "sine",
+    "tu",
+    "re",
+    "ine",
+    "asikne",
+    "iwan",
+    "arwan",
+    "tupesan",
+    "sinepesan",
+];
+
+/// Largest number that can be spelled with [`number_to_words`].
+const MAX_NUMBER: u32 = 399;
+
+/// Decimal points and thousands separators written between digits.
+const NUMBER_SEPARATORS: &str = ".,．，";
+
+/// Spell a number of twenties (`hotne`).
+fn spell_twenties(count: u32) -> String {
+    match count {
+        1 => "hotne".to_owned(),
+        _ => format!("{} hotne", spell(count)),
+    }
+}
+
+/// Spell a number from 1 to [`MAX_NUMBER`] in romanized Ainu.
+fn spell(n: u32) -> String {
+    let (twenties, rest) = (n / 20, n % 20);
+    let (base, remainder) = match (twenties, rest) {
+        (0, 0..=9) => return UNITS[n as usize - 1].to_owned(),
+        (0, _) => ("wan".to_owned(), rest - 10),
+        (_, 0..=9) => (spell_twenties(twenties), rest),
+        // 30 is "ten less than two twenties"
+        (_, _) => (format!("wan e {}", spell_twenties(twenties + 1)), rest - 10),
+    };
+
+    match remainder {
+        0 => base,
+        _ => format!("{} ikasma {}", spell(remainder), base),
+    }
+}
+
+/// Spell a number with Ainu numerals
+///
+/// Ainu counts in twenties: numbers above ten are added to a ten or a twenty with
+/// `ikasma` (`sine ikasma wan`, 11), and an odd ten is ten less than the next twenty
+/// (`wan e tu hotne`, 30).
+///
+/// # Arguments
+///
+/// * `n` - The number, from 1 to 399.
+/// * `script` - The script of the output.
+///
+/// # Returns
+///
+/// * `Result<String, Error>` - The spelled number, or [`Error::NumberOutOfRange`] if
+///   it cannot be spelled.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{number_to_words, Script};
+/// assert_eq!(number_to_words(5, Script::Latn).unwrap(), "asikne");
+/// assert_eq!(number_to_words(11, Script::Latn).unwrap(), "sine ikasma wan");
+/// assert_eq!(number_to_words(30, Script::Latn).unwrap(), "wan e tu hotne");
+/// assert_eq!(number_to_words(20, Script::Kana).unwrap(), "ホッネ");
+/// assert!(number_to_words(0, Script::Latn).is_err());
+/// ```
+pub fn number_to_words(n: u32, script: Script) -> Result<String, Error> {
+    if !(1..=MAX_NUMBER).contains(&n) {
+        return Err(Error::NumberOutOfRange(n));
+    }
+    Ok(convert_to(&spell(n), script))
+}
+
+/// Spell out the numbers written with digits in a text
+///
+/// ASCII and full-width digits are replaced with Ainu numerals. Numbers that cannot be
+/// spelled with [`number_to_words`], decimals and numbers with thousands separators
+/// (`3.5`, `1,000`) are kept as they are.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the text.
+/// * `script` - The script of the numerals.
+///
+/// # Returns
+///
+/// * `String` - The text with its numbers spelled out.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{expand_numbers, Script};
+/// assert_eq!(expand_numbers("2 cise", Script::Latn), "tu cise");
+/// assert_eq!(expand_numbers("1000", Script::Latn), "1000");
+/// assert_eq!(expand_numbers("3.5", Script::Latn), "3.5");
+/// ```
+pub fn expand_numbers(text: &str, script: Script) -> String {
+    let mut result = String::new();
+    let mut digits = String::new();
+
+    let flush = |digits: &mut String, result: &mut String| {
+        if digits.is_empty() {
+            return;
+        }
+        // Decimals and grouped thousands are not spelled out
+        let spelled = match digits.contains(|c| NUMBER_SEPARATORS.contains(c)) {
+            true => None,
+            false => digits
+                .chars()
+                .map(|c| c.to_digit(10).unwrap_or_else(|| c as u32 - '０' as u32))
+                .try_fold(0u32, |n, digit| n.checked_mul(10)?.checked_add(digit))
+                .and_then(|n| number_to_words(n, script).ok()),
+        };
+        match spelled {
+            Some(words) => result.push_str(&words),
+            None => result.push_str(digits),
+        }
+        digits.clear();
+    };
+
+    let is_digit = |c: &char| c.is_ascii_digit() || ('０'..='９').contains(c);
+    let chars: Vec<char> = text.chars().collect();
+    for (i, &c) in chars.iter().enumerate() {
+        let between_digits = !digits.is_empty() && chars.get(i + 1).is_some_and(is_digit);
+        if is_digit(&c) || (NUMBER_SEPARATORS.contains(c) && between_digits) {
+            digits.push(c);
+        } else {
+            flush(&mut digits, &mut result);
+            result.push(c);
+        }
+    }
+    flush(&mut digits, &mut result);
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn spells_twenties() {
+        assert_eq!(spell(21), "sine ikasma hotne");
+        assert_eq!(spell(35), "asikne ikasma wan e tu hotne");
+        assert_eq!(spell(100), "asikne hotne");
+        assert_eq!(spell(399), "sinepesan ikasma wan e hotne hotne");
+        assert_eq!(
+            number_to_words(400, Script::Latn),
+            Err(Error::NumberOutOfRange(400))
+        );
+    }
+
+    #[test]
+    fn keeps_decimals_and_grouped_numbers() {
+        assert_eq!(expand_numbers("3.5 1,000", Script::Latn), "3.5 1,000");
+        assert_eq!(expand_numbers("3. 2, 1", Script::Latn), "re. tu, sine");
+    }
+
+    #[test]
+    fn expands_full_width_digits() {
+        assert_eq!(expand_numbers("３ チセ", Script::Kana), "レ チセ");
+    }
+}
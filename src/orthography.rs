@@ -0,0 +1,259 @@
+use crate::conversion::katakana::convert_kana_to_latn;
+use crate::conversion::latin::{CONSONANTS, GLOTTAL_STOPS, VOWELS};
+use crate::detection::detect;
+use crate::util::{is_ainu_word, remove_acute_accent, SplitIntoWords};
+use crate::Script;
+use unicode_normalization::UnicodeNormalization;
+
+/// Spelling conventions of Ainu texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orthography {
+    /// Modern romanization: `c`, `s`, glides written `y` and `w` (`kamuy`, `cise`)
+    Latin,
+    /// Batchelor's romanization: `ch`, `sh`, glides written `i` and `u`, and codas
+    /// without an added vowel (`kamui`, `chise`, `tush`)
+    Batchelor,
+    /// Japanese-influenced (Hepburn) spelling: `shi`, `chi`, `tsu`, `fu`, and a vowel
+    /// after every coda but `n` (`kamui`, `shikotsu`)
+    Hepburn,
+    /// Katakana with `ト゚` for `tu` and small kana for a coda `r` matching the vowel
+    /// (`カㇻ`, `キㇼ`)
+    KanaHarmonic,
+    /// Katakana with `トゥ` for `tu` and `ㇽ` for every coda `r`
+    KanaUniform,
+    /// Modern Ainu Cyrillic, with `ц` for `c`
+    CyrlStandard,
+    /// Russian convention, with `ч` for `c`
+    CyrlRussian,
+    /// Nineteenth-century spellings such as Dobrotvorsky's, with pre-reform and voiced
+    /// letters
+    CyrlDobrotvorsky,
+}
+
+/// The most likely orthography of the part of a text written in one script.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrthographyGuess {
+    /// The script of the words considered.
+    pub script: Script,
+    /// The most likely orthography.
+    pub orthography: Orthography,
+    /// Confidence from 0 to 1. A text without any distinguishing spelling gets an
+    /// equal share for every orthography of its script.
+    pub confidence: f64,
+}
+
+/// Orthographies of each script, the fallback first.
+const ORTHOGRAPHIES: [(Script, &[Orthography]); 3] = [
+    (
+        Script::Latn,
+        &[
+            Orthography::Latin,
+            Orthography::Batchelor,
+            Orthography::Hepburn,
+        ],
+    ),
+    (
+        Script::Kana,
+        &[Orthography::KanaHarmonic, Orthography::KanaUniform],
+    ),
+    (
+        Script::Cyrl,
+        &[
+            Orthography::CyrlStandard,
+            Orthography::CyrlRussian,
+            Orthography::CyrlDobrotvorsky,
+        ],
+    ),
+];
+
+/// Spelling features of a romanized word, as the orthographies they point to.
+fn latin_evidence(word: &str) -> Vec<Orthography> {
+    let word = remove_acute_accent(word).to_lowercase();
+    let chars: Vec<char> = word.chars().collect();
+    let mut evidence = Vec::new();
+
+    if word.contains('=') || word.chars().any(|c| GLOTTAL_STOPS.contains(c)) {
+        evidence.push(Orthography::Latin);
+    }
+    for (i, &c) in chars.iter().enumerate() {
+        let next = chars.get(i + 1).copied();
+        let after_vowel = i > 0 && VOWELS.contains(chars[i - 1]);
+        match (c, next) {
+            ('c' | 's', Some('h')) => {
+                evidence.extend([Orthography::Batchelor, Orthography::Hepburn]);
+            }
+            ('c', _) | ('s', Some('i')) => evidence.push(Orthography::Latin),
+            ('y' | 'w', None) if after_vowel => evidence.push(Orthography::Latin),
+            ('y' | 'w', Some(next)) if after_vowel && !VOWELS.contains(next) => {
+                evidence.push(Orthography::Latin)
+            }
+            ('j' | 'f', _) => evidence.push(Orthography::Hepburn),
+            ('t', Some('s')) => evidence.push(Orthography::Hepburn),
+            _ => {}
+        }
+    }
+    if word.nfd().any(|c| c == '\u{304}') {
+        evidence.push(Orthography::Hepburn);
+    }
+
+    let vowel_glide = ["ai", "ui", "oi", "ei", "au"]
+        .iter()
+        .any(|glide| word.ends_with(glide));
+    if vowel_glide {
+        evidence.extend([Orthography::Batchelor, Orthography::Hepburn]);
+    }
+    if word.ends_with("sh") || word.ends_with("ch") {
+        evidence.push(Orthography::Batchelor);
+    } else if word.ends_with(|c| CONSONANTS.contains(c) && c != 'n') {
+        evidence.extend([Orthography::Latin, Orthography::Batchelor]);
+    }
+
+    evidence
+}
+
+/// Spelling features of a Katakana word, as the orthographies they point to.
+fn kana_evidence(word: &str) -> Vec<Orthography> {
+    let chars: Vec<char> = word.nfc().collect();
+    let mut evidence = Vec::new();
+
+    for (i, &c) in chars.iter().enumerate() {
+        let next = chars.get(i + 1).copied();
+        match (c, next) {
+            ('ト', Some('\u{309A}')) => evidence.push(Orthography::KanaHarmonic),
+            ('ト', Some('ゥ')) => evidence.push(Orthography::KanaUniform),
+            ('ㇻ' | 'ㇼ' | 'ㇽ' | 'ㇾ' | 'ㇿ', _) if i > 0 => {
+                let vowel = convert_kana_to_latn(&chars[i - 1].to_string()).pop();
+                let harmonic = match vowel {
+                    Some('a') => 'ㇻ',
+                    Some('i') => 'ㇼ',
+                    Some('u') => 'ㇽ',
+                    Some('e') => 'ㇾ',
+                    Some('o') => 'ㇿ',
+                    _ => continue,
+                };
+                if c == harmonic && c != 'ㇽ' {
+                    evidence.push(Orthography::KanaHarmonic);
+                } else if c == 'ㇽ' && harmonic != 'ㇽ' {
+                    evidence.push(Orthography::KanaUniform);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    evidence
+}
+
+/// Spelling features of a Cyrillic word, as the orthographies they point to.
+fn cyrl_evidence(word: &str) -> Vec<Orthography> {
+    word.to_lowercase()
+        .chars()
+        .flat_map(|c| match c {
+            'ц' => vec![Orthography::CyrlStandard],
+            'ч' => vec![Orthography::CyrlRussian, Orthography::CyrlDobrotvorsky],
+            'ѣ' | 'і' | 'ї' | 'ѵ' | 'ѳ' | 'б' | 'д' | 'г' | 'з' | 'ж' | 'ш' | 'щ' | 'ы' | 'л'
+            | 'ф' => vec![Orthography::CyrlDobrotvorsky],
+            _ => vec![],
+        })
+        .collect()
+}
+
+/// Guess the spelling conventions of an Ainu text
+///
+/// Each word is assigned to its script with [`detect`](crate::detect), and spellings
+/// that only some conventions use are counted as evidence: `c` against `ch`, `kamuy`
+/// against `kamui`, `ト゚` against `トゥ`, `ц` against `ч`, and so on.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the Ainu text.
+///
+/// # Returns
+///
+/// * `Vec<OrthographyGuess>` - The most likely orthography of each script found in
+///   the text, in the order Latin, Katakana, Cyrillic.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{detect_orthography, Orthography, Script};
+/// let guesses = detect_orthography("Kamui chisei");
+/// assert_eq!(guesses[0].script, Script::Latn);
+/// assert_eq!(guesses[0].orthography, Orthography::Batchelor);
+///
+/// let guesses = detect_orthography("shikotsu fuji");
+/// assert_eq!(guesses[0].orthography, Orthography::Hepburn);
+///
+/// let guesses = detect_orthography("ト゚ㇺ カㇻ");
+/// assert_eq!(guesses[0].orthography, Orthography::KanaHarmonic);
+/// assert!(guesses[0].confidence > 0.5);
+/// ```
+pub fn detect_orthography(text: &str) -> Vec<OrthographyGuess> {
+    let mut evidence: Vec<(Script, Vec<Orthography>)> = Vec::new();
+    for word in text.split_into_words() {
+        if !is_ainu_word(&word) {
+            continue;
+        }
+        let script = detect(&word);
+        let found = match script {
+            Script::Latn => latin_evidence(&word),
+            Script::Kana => kana_evidence(&word),
+            Script::Cyrl => cyrl_evidence(&word),
+            _ => continue,
+        };
+        match evidence.iter_mut().find(|(s, _)| *s == script) {
+            Some((_, orthographies)) => orthographies.extend(found),
+            None => evidence.push((script, found)),
+        }
+    }
+
+    ORTHOGRAPHIES
+        .iter()
+        .filter_map(|(script, orthographies)| {
+            let (_, found) = evidence.iter().find(|(s, _)| s == script)?;
+            // Every orthography starts with one point, so that the fallback wins ties
+            let scores: Vec<usize> = orthographies
+                .iter()
+                .map(|o| 1 + found.iter().filter(|f| *f == o).count())
+                .collect();
+            let best = (0..scores.len()).fold(
+                0,
+                |best, i| {
+                    if scores[i] > scores[best] {
+                        i
+                    } else {
+                        best
+                    }
+                },
+            );
+            Some(OrthographyGuess {
+                script: *script,
+                orthography: orthographies[best],
+                confidence: scores[best] as f64 / scores.iter().sum::<usize>() as f64,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn guesses_each_script() {
+        let guesses = detect_orthography("kamuy cise トゥ チセ");
+        assert_eq!(guesses.len(), 2);
+        assert_eq!(guesses[0].orthography, Orthography::Latin);
+        assert_eq!(guesses[1].orthography, Orthography::KanaUniform);
+
+        let guesses = detect_orthography("мачия");
+        assert_eq!(guesses[0].orthography, Orthography::CyrlRussian);
+    }
+
+    #[test]
+    fn falls_back_without_evidence() {
+        let guesses = detect_orthography("pirka");
+        assert_eq!(guesses[0].orthography, Orthography::Latin);
+        assert!((guesses[0].confidence - 1.0 / 3.0).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,159 @@
+use std::ops::Range;
+
+/// Opening delimiter of an escaped region for [`convert_escaped`].
+const ESCAPE_OPEN: &str = "{{";
+/// Closing delimiter of an escaped region for [`convert_escaped`].
+const ESCAPE_CLOSE: &str = "}}";
+
+/// Convert a text with a converter, leaving protected ranges untouched
+///
+/// The parts of the text outside the protected ranges are converted one by one, and
+/// the protected ranges are copied byte for byte. Overlapping ranges are merged, empty
+/// and inverted ranges are ignored, and ranges past the end of the text are cut off.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the text.
+/// * `protected` - Byte ranges of the text to leave untouched.
+/// * `convert` - The converter, such as [`convert_latn_to_kana`](crate::convert_latn_to_kana).
+///
+/// # Returns
+///
+/// * `String` - The converted text.
+///
+/// # Panics
+///
+/// Panics if a range does not start or end on a character boundary.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_latn_to_kana, convert_protected};
+/// let text = "aynu https://ainu.example ne";
+/// assert_eq!(
+///     convert_protected(text, &[5..25], convert_latn_to_kana),
+///     "アイヌ https://ainu.example ネ"
+/// );
+/// ```
+pub fn convert_protected<F>(text: &str, protected: &[Range<usize>], convert: F) -> String
+where
+    F: Fn(&str) -> String,
+{
+    // Empty and inverted ranges protect nothing and must not split the text
+    let mut ranges: Vec<Range<usize>> = protected
+        .iter()
+        .filter(|range| range.start < range.end.min(text.len()))
+        .map(|range| range.start..range.end.min(text.len()))
+        .collect();
+    ranges.sort_by_key(|range| range.start);
+
+    let mut merged: Vec<Range<usize>> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+
+    let mut result = String::new();
+    let mut position = 0;
+    for range in merged {
+        if range.start > position {
+            result.push_str(&convert(&text[position..range.start]));
+        }
+        result.push_str(&text[range.clone()]);
+        position = range.end;
+    }
+    if position < text.len() {
+        result.push_str(&convert(&text[position..]));
+    }
+
+    result
+}
+
+/// Convert a text with a converter, leaving regions escaped with `{{...}}` untouched
+///
+/// The delimiters are removed and the text between them is copied byte for byte. A
+/// `{{` without a matching `}}` is converted as ordinary text.
+///
+/// # Arguments
+///
+/// * `text` - A string slice that holds the text.
+/// * `convert` - The converter, such as [`convert_latn_to_kana`](crate::convert_latn_to_kana).
+///
+/// # Returns
+///
+/// * `String` - The converted text, without the escape delimiters.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{convert_escaped, convert_latn_to_kana};
+/// assert_eq!(
+///     convert_escaped("kamuy {{神}} ne", convert_latn_to_kana),
+///     "カムイ 神 ネ"
+/// );
+/// ```
+pub fn convert_escaped<F>(text: &str, convert: F) -> String
+where
+    F: Fn(&str) -> String,
+{
+    let mut result = String::new();
+    let mut position = 0;
+    while let Some(open) = text[position..].find(ESCAPE_OPEN) {
+        let open = position + open;
+        let content = open + ESCAPE_OPEN.len();
+        let Some(close) = text[content..].find(ESCAPE_CLOSE) else {
+            break;
+        };
+        let close = content + close;
+        if open > position {
+            result.push_str(&convert(&text[position..open]));
+        }
+        result.push_str(&text[content..close]);
+        position = close + ESCAPE_CLOSE.len();
+    }
+    if position < text.len() {
+        result.push_str(&convert(&text[position..]));
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{convert_kana_to_latn, convert_latn_to_kana};
+
+    #[test]
+    fn merges_overlapping_ranges() {
+        assert_eq!(
+            convert_protected("kamuy ne", &[0..3, 2..5, 40..50], convert_latn_to_kana),
+            "kamuy ネ"
+        );
+        assert_eq!(
+            convert_protected("aynu ne", &[0..2, 1..4], convert_latn_to_kana),
+            "aynu ネ"
+        );
+    }
+
+    #[test]
+    fn ignores_empty_and_inverted_ranges() {
+        assert_eq!(
+            convert_protected(
+                "aynu ne",
+                &[Range { start: 3, end: 1 }, 2..2],
+                convert_latn_to_kana
+            ),
+            "アイヌ ネ"
+        );
+    }
+
+    #[test]
+    fn keeps_unclosed_escape() {
+        assert_eq!(
+            convert_escaped("{{カムイ}} ネ {{", convert_kana_to_latn),
+            "カムイ ne {{"
+        );
+    }
+}
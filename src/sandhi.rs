@@ -0,0 +1,264 @@
+use crate::conversion::latin::VOWELS;
+use crate::syllable::separate;
+use std::fmt;
+use unicode_normalization::UnicodeNormalization;
+
+/// Sound changes at the join of two Ainu morphemes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SandhiRule {
+    /// `r` becomes `n` before `n` or `r` (`kor` + `nispa` → `konnispa`)
+    RToN,
+    /// `r` becomes `t` before `t` or `c` (`kor` + `cise` → `kotcise`)
+    RToT,
+    /// `n` becomes `y` before `s` or `y` (`pon` + `sike` → `poysike`)
+    NToY,
+    /// `n` becomes `m` before `p` or `m` (`pon` + `menoko` → `pommenoko`)
+    NToM,
+    /// One of two identical vowels is dropped (`ruwe` + `ene` → `ruwene`)
+    VowelElision,
+    /// `y` is dropped before `i`, and `w` before `u` (`kay` + `i` → `kai`)
+    GlideElision,
+}
+
+impl SandhiRule {
+    /// A short explanation of the rule.
+    pub fn description(&self) -> &'static str {
+        match self {
+            SandhiRule::RToN => "r becomes n before n or r",
+            SandhiRule::RToT => "r becomes t before t or c",
+            SandhiRule::NToY => "n becomes y before s or y",
+            SandhiRule::NToM => "n becomes m before p or m",
+            SandhiRule::VowelElision => "one of two identical vowels is dropped",
+            SandhiRule::GlideElision => "y is dropped before i, and w before u",
+        }
+    }
+}
+
+/// A rule applied at the join of two morphemes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SandhiChange {
+    /// The rule that was applied.
+    pub rule: SandhiRule,
+    /// Index of the morpheme before the join.
+    pub join: usize,
+    /// Last sound of the morpheme before the join.
+    pub left: char,
+    /// First sound of the morpheme after the join.
+    pub right: char,
+}
+
+impl fmt::Display for SandhiChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let result = match self.rule {
+            SandhiRule::RToN => format!("n{}", self.right),
+            SandhiRule::RToT => format!("t{}", self.right),
+            SandhiRule::NToY => format!("y{}", self.right),
+            SandhiRule::NToM => format!("m{}", self.right),
+            SandhiRule::VowelElision | SandhiRule::GlideElision => self.right.to_string(),
+        };
+        write!(
+            f,
+            "{} + {} → {}: {}",
+            self.left,
+            self.right,
+            result,
+            self.rule.description()
+        )
+    }
+}
+
+/// A word built from morphemes by [`surface_form`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SurfaceForm {
+    /// The romanized word as it is pronounced and written.
+    pub surface: String,
+    /// The rules applied, in the order of the joins.
+    pub changes: Vec<SandhiChange>,
+}
+
+/// The rule for a join and the sound replacing the last sound of the left morpheme.
+fn find_rule(left: char, right: char, boundary: bool) -> Option<(SandhiRule, Option<char>)> {
+    match (left, right) {
+        ('r', 'n' | 'r') => Some((SandhiRule::RToN, Some('n'))),
+        ('r', 't' | 'c') => Some((SandhiRule::RToT, Some('t'))),
+        ('n', 's' | 'y') => Some((SandhiRule::NToY, Some('y'))),
+        ('n', 'p' | 'm') => Some((SandhiRule::NToM, Some('m'))),
+        // Both vowels of a personal affix are written (`ku=uk`)
+        (l, r) if l == r && VOWELS.contains(l) && !boundary => {
+            Some((SandhiRule::VowelElision, None))
+        }
+        ('y', 'i') | ('w', 'u') if !boundary => Some((SandhiRule::GlideElision, None)),
+        _ => None,
+    }
+}
+
+/// Join romanized Ainu morphemes, applying the sound changes at each join
+///
+/// A coda `r` assimilates to a following `n`, `r`, `t` or `c`, a coda `n` becomes `y`
+/// before `s` and `y` and `m` before `p` and `m`, and identical vowels and a glide
+/// before its own vowel are merged. A `=` at a join marks a personal affix: it is
+/// kept, and only the consonant changes apply across it.
+///
+/// # Arguments
+///
+/// * `morphemes` - The romanized morphemes, in lower case.
+///
+/// # Returns
+///
+/// * `SurfaceForm` - The joined word with an explanation of each rule applied.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{surface_form, SandhiRule};
+/// let form = surface_form(&["kor", "nispa"]);
+/// assert_eq!(form.surface, "konnispa");
+/// assert_eq!(form.changes[0].rule, SandhiRule::RToN);
+/// assert_eq!(
+///     form.changes[0].to_string(),
+///     "r + n → nn: r becomes n before n or r"
+/// );
+///
+/// assert_eq!(surface_form(&["ku=", "kor"]).surface, "ku=kor");
+/// ```
+pub fn surface_form(morphemes: &[&str]) -> SurfaceForm {
+    let mut surface = String::new();
+    let mut changes = Vec::new();
+
+    for (i, morpheme) in morphemes.iter().enumerate() {
+        let morpheme = morpheme.nfc().collect::<String>();
+        let boundary = surface.ends_with('=') || morpheme.starts_with('=');
+        let left = surface.trim_end_matches('=').char_indices().last();
+        let right = morpheme.trim_start_matches('=').chars().next();
+
+        if let (Some((index, left)), Some(right)) = (left, right) {
+            if let Some((rule, replacement)) = find_rule(left, right, boundary) {
+                let replacement = replacement.map(String::from).unwrap_or_default();
+                surface.replace_range(index..index + left.len_utf8(), &replacement);
+                changes.push(SandhiChange {
+                    rule,
+                    join: i - 1,
+                    left,
+                    right,
+                });
+            }
+        }
+        surface.push_str(&morpheme);
+    }
+
+    SurfaceForm { surface, changes }
+}
+
+/// Whether a string could be an Ainu morpheme: every syllable has a vowel and at most
+/// one coda.
+fn is_possible_morpheme(latn: &str) -> bool {
+    let syllables = separate(latn.trim_matches('='));
+    !syllables.is_empty()
+        && syllables.iter().all(|syllable| {
+            match syllable.chars().position(|c| VOWELS.contains(c)) {
+                Some(vowel) => syllable.chars().count() - vowel <= 2,
+                None => false,
+            }
+        })
+}
+
+/// Find the pairs of morphemes that [`surface_form`] joins into a romanized word
+///
+/// Every split of the word into two possible morphemes is tried, undoing the sound
+/// changes of [`surface_form`] at the split (`konnispa` → `kor` + `nispa`). The
+/// candidates are not checked against a dictionary, so most of them are not real
+/// morphemes; join them again with [`surface_form`] for an explanation.
+///
+/// # Arguments
+///
+/// * `surface` - The romanized word, in lower case.
+///
+/// # Returns
+///
+/// * `Vec<Vec<String>>` - The candidate segmentations, ordered by the position of the
+///   split.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::underlying_forms;
+/// let candidates = underlying_forms("konnispa");
+/// assert!(candidates.contains(&vec!["kor".to_owned(), "nispa".to_owned()]));
+/// assert!(candidates.contains(&vec!["kon".to_owned(), "nispa".to_owned()]));
+/// ```
+pub fn underlying_forms(surface: &str) -> Vec<Vec<String>> {
+    let surface = surface.nfc().collect::<String>();
+    let mut candidates: Vec<Vec<String>> = Vec::new();
+
+    for (split, _) in surface.char_indices().skip(1) {
+        let (left, right) = surface.split_at(split);
+        let last = left.trim_end_matches('=').chars().last();
+        let first = right.trim_start_matches('=').chars().next();
+        let (Some(last), Some(first)) = (last, first) else {
+            continue;
+        };
+        let stem = &left[..left.trim_end_matches('=').len() - last.len_utf8()];
+        let affix = &left[stem.len() + last.len_utf8()..];
+
+        let mut pairs = vec![(left.to_owned(), right.to_owned())];
+        match (last, first) {
+            ('n', 'n' | 'r') | ('t', 't' | 'c') => {
+                pairs.push((format!("{}r{}", stem, affix), right.to_owned()))
+            }
+            ('y', 's' | 'y') | ('m', 'p' | 'm') => {
+                pairs.push((format!("{}n{}", stem, affix), right.to_owned()))
+            }
+            _ => {}
+        }
+        if VOWELS.contains(last) {
+            pairs.push((left.to_owned(), format!("{}{}", last, right)));
+        }
+        match first {
+            'i' => pairs.push((format!("{}y", left), right.to_owned())),
+            'u' => pairs.push((format!("{}w", left), right.to_owned())),
+            _ => {}
+        }
+
+        for (left, right) in pairs {
+            let candidate = vec![left, right];
+            if is_possible_morpheme(&candidate[0])
+                && is_possible_morpheme(&candidate[1])
+                && surface_form(&[&candidate[0], &candidate[1]]).surface == surface
+                && !candidates.contains(&candidate)
+            {
+                candidates.push(candidate);
+            }
+        }
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn applies_rules_at_each_join() {
+        assert_eq!(surface_form(&["kor", "cise"]).surface, "kotcise");
+        assert_eq!(surface_form(&["pon", "menoko"]).surface, "pommenoko");
+        assert_eq!(surface_form(&["ruwe", "ene"]).surface, "ruwene");
+        assert_eq!(surface_form(&["ku=", "uk"]).surface, "ku=uk");
+
+        let form = surface_form(&["kor", "rusuy", "pa"]);
+        assert_eq!(form.surface, "konrusuypa");
+        assert_eq!(form.changes.len(), 1);
+        assert_eq!(form.changes[0].join, 0);
+    }
+
+    #[test]
+    fn undoes_rules() {
+        let candidates = underlying_forms("kotcise");
+        assert!(candidates.contains(&vec!["kor".to_owned(), "cise".to_owned()]));
+        assert!(!candidates.contains(&vec!["k".to_owned(), "otcise".to_owned()]));
+
+        let candidates = underlying_forms("poysike");
+        assert!(candidates.contains(&vec!["pon".to_owned(), "sike".to_owned()]));
+        assert!(candidates.contains(&vec!["poy".to_owned(), "sike".to_owned()]));
+    }
+}
@@ -1,9 +1,14 @@
-use crate::conversion::latin::{CONSONANTS, VOWELS};
+use crate::conversion::latin::{
+    GlottalStop, CONSONANTS, DEFAULT_HIATUS_MARKS, HIATUS_MARKS, VOWELS,
+};
 use std::collections::HashMap;
+use std::collections::HashSet;
 
 /// Syllabify an Ainu word
 ///
-/// Divide a romanized Ainu word into syllables.
+/// Divide a romanized Ainu word into syllables. A hiatus mark `·` forces a syllable
+/// boundary, so that `kay·e` is split as `kay-e` rather than `ka-ye`. Glottal stops are
+/// written `’`.
 /// # Arguments
 /// * `latn` - A string slice that holds the romanized Ainu word.
 ///  
@@ -14,17 +19,70 @@ use std::collections::HashMap;
 /// println!("{:?}", separated); // ["pro", "gram", "ming", "is", "fun"]
 /// ```
 pub fn separate(latn: &str) -> Vec<String> {
+    separate_with(latn, &SeparateOptions::default())
+}
+
+/// Options for [`separate_with`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SeparateOptions {
+    /// How to write glottal stops in the syllables.
+    pub glottal_stop: GlottalStop,
+    /// Read `.` as a hiatus mark (`kay.e` → `kay-e`) as well as `·`.
+    pub dot_hiatus_mark: bool,
+}
+
+/// Syllabify an Ainu word with the given options
+///
+/// # Arguments
+/// * `latn` - A string slice that holds the romanized Ainu word.
+/// * `options` - Syllabification options, see [`SeparateOptions`].
+///
+/// # Example
+/// ```
+/// use ainconv::{separate_with, GlottalStop, SeparateOptions};
+/// let options = SeparateOptions {
+///     glottal_stop: GlottalStop::Omitted,
+///     ..Default::default()
+/// };
+/// assert_eq!(separate_with("a'e", &options), vec!["a", "e"]);
+///
+/// let options = SeparateOptions {
+///     dot_hiatus_mark: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(separate_with("kay.e", &options), vec!["kay", "e"]);
+/// ```
+pub fn separate_with(latn: &str, options: &SeparateOptions) -> Vec<String> {
+    let hiatus_marks = match options.dot_hiatus_mark {
+        true => HIATUS_MARKS,
+        false => DEFAULT_HIATUS_MARKS,
+    };
+    syllabify(latn, hiatus_marks)
+        .iter()
+        .map(|s| options.glottal_stop.normalize(s))
+        .collect()
+}
+
+fn syllabify(latn: &str, hiatus_marks: &str) -> Vec<String> {
     // Index by CHARACTER throughout (not byte), so multi-byte characters such as
     // the glottal stop ’ (U+2019) are handled correctly rather than corrupting the
     // syllable map (and, downstream, panicking on a non-char-boundary slice).
-    let chars: Vec<char> = latn.chars().collect();
+    let mut chars: Vec<char> = Vec::new();
+    let mut boundaries: HashSet<usize> = HashSet::new();
+    for c in latn.chars() {
+        if hiatus_marks.contains(c) {
+            boundaries.insert(chars.len());
+        } else {
+            chars.push(c);
+        }
+    }
     let n = chars.len();
     let mut syllable_map: HashMap<usize, usize> = HashMap::new();
     let mut syllable_count = 1;
 
     for i in 0..n {
         if VOWELS.contains(chars[i]) {
-            if i > 0 && CONSONANTS.contains(chars[i - 1]) {
+            if i > 0 && CONSONANTS.contains(chars[i - 1]) && !boundaries.contains(&i) {
                 syllable_map.insert(i - 1, syllable_count);
             }
             syllable_map.insert(i, syllable_count);
@@ -57,8 +115,7 @@ pub fn separate(latn: &str) -> Vec<String> {
     }
     syllables.push(chars[head..].iter().collect());
 
-    // Remove apostrophes from syllables.
-    syllables.iter().map(|s| s.replace('\'', "")).collect()
+    syllables
 }
 
 #[cfg(test)]
@@ -77,6 +134,13 @@ mod tests {
         // mixing byte and char indices used to corrupt the map and could panic.
         assert_eq!(separate("ne\u{2019}"), vec!["ne\u{2019}"]);
         assert_eq!(separate("a\u{2019}e"), vec!["a", "\u{2019}e"]);
+        assert_eq!(separate("a'e"), vec!["a", "\u{2019}e"]);
+        assert_eq!(separate("a\u{2bc}e"), vec!["a", "\u{2019}e"]);
+        let options = SeparateOptions {
+            glottal_stop: GlottalStop::Omitted,
+            ..Default::default()
+        };
+        assert_eq!(separate_with("a'e", &options), vec!["a", "e"]);
     }
 
     #[test]
@@ -85,4 +149,17 @@ mod tests {
         let _ = separate("k");
         let _ = separate("");
     }
+
+    #[test]
+    fn separate_honours_hiatus_mark() {
+        assert_eq!(separate("kaye"), vec!["ka", "ye"]);
+        assert_eq!(separate("ku·uk"), vec!["ku", "uk"]);
+        assert_eq!(separate("aynu.ne"), vec!["ay", "nu.", "ne"]);
+
+        let options = SeparateOptions {
+            dot_hiatus_mark: true,
+            ..Default::default()
+        };
+        assert_eq!(separate_with("kay.e", &options), vec!["kay", "e"]);
+    }
 }
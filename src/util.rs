@@ -1,13 +1,36 @@
+use crate::conversion::latin::{DEFAULT_HIATUS_MARKS, HIATUS_MARKS};
 use unicode_normalization::UnicodeNormalization;
 
 pub fn is_katakana(c: char) -> bool {
     ('\u{30A1}'..='\u{31FF}').contains(&c)
 }
 
+pub fn is_latin(c: char) -> bool {
+    c.is_ascii_alphabetic()
+        || ('\u{00C0}'..='\u{024F}').contains(&c)
+        || ('\u{1E00}'..='\u{1EFF}').contains(&c)
+}
+
+pub fn is_hiragana(c: char) -> bool {
+    ('\u{3041}'..='\u{3096}').contains(&c)
+}
+
+pub fn is_half_width_katakana(c: char) -> bool {
+    ('\u{FF66}'..='\u{FF9D}').contains(&c)
+}
+
 pub fn is_cyrillic(c: char) -> bool {
     ('\u{0400}'..='\u{04FF}').contains(&c)
 }
 
+/// The katakana corresponding to a hiragana, or the character itself.
+pub fn to_katakana(c: char) -> char {
+    match c {
+        '\u{3041}'..='\u{3096}' => char::from_u32(c as u32 + 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
 // pub fn is_hangul(c: char) -> bool {
 //     let re = Regex::new(r"\p{Script_Extensions=Hangul}").unwrap();
 //     re.is_match(c.to_string().as_str())
@@ -38,14 +61,36 @@ fn test_remove_accent() {
     assert_eq!(remove_acute_accent("cá̄"), "cā");
 }
 
+/// Capitalize the first letter of a word.
+pub fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Capitalize the output if the source word was capitalized.
+pub fn match_case(source: &str, output: &str) -> String {
+    match source.chars().next() {
+        Some(first) if first.is_uppercase() => capitalize(output),
+        _ => output.to_owned(),
+    }
+}
+
 // Define the SplitIntoWords trait with the split_into_words method
 pub trait SplitIntoWords {
-    fn split_into_words(&self) -> Vec<String>;
+    fn split_into_words(&self) -> Vec<String> {
+        self.split_into_words_with_hiatus(DEFAULT_HIATUS_MARKS)
+    }
+
+    /// Split into words, keeping words joined by one of `hiatus_marks` (`a·i`) together
+    fn split_into_words_with_hiatus(&self, hiatus_marks: &str) -> Vec<String>;
 }
 
 // Implement the SplitIntoWords trait for the String type
 impl SplitIntoWords for String {
-    fn split_into_words(&self) -> Vec<String> {
+    fn split_into_words_with_hiatus(&self, hiatus_marks: &str) -> Vec<String> {
         // Return an empty vector if the string is empty
         if self.is_empty() {
             return Vec::new();
@@ -77,13 +122,30 @@ impl SplitIntoWords for String {
         // Push the last word to the result
         result.push(current);
 
-        result
+        // Join words split by a hiatus mark (`a·i`) back together
+        let mut merged: Vec<String> = Vec::new();
+        let mut words = result.into_iter().peekable();
+        while let Some(word) = words.next() {
+            let is_hiatus_mark = word.chars().count() == 1 && hiatus_marks.contains(word.as_str());
+            let between_letters = merged.last().is_some_and(|w| is_ainu_word(w))
+                && words.peek().is_some_and(|w| is_ainu_word(w));
+            if is_hiatus_mark && between_letters {
+                let mut joined = merged.pop().unwrap();
+                joined.push_str(&word);
+                joined.push_str(&words.next().unwrap());
+                merged.push(joined);
+            } else {
+                merged.push(word);
+            }
+        }
+
+        merged
     }
 }
 
 impl SplitIntoWords for &str {
-    fn split_into_words(&self) -> Vec<String> {
-        self.to_string().split_into_words()
+    fn split_into_words_with_hiatus(&self, hiatus_marks: &str) -> Vec<String> {
+        self.to_string().split_into_words_with_hiatus(hiatus_marks)
     }
 }
 
@@ -94,6 +156,16 @@ fn test_split_into_words() {
         text.split_into_words(),
         vec!["Hello", "、", "世界", "! ", "This", " ", "is", " ", "Rust", "."]
     );
+
+    let text = String::from("kay.e a·i. ne");
+    assert_eq!(
+        text.split_into_words(),
+        vec!["kay", ".", "e", " ", "a·i", ". ", "ne"]
+    );
+    assert_eq!(
+        text.split_into_words_with_hiatus(HIATUS_MARKS),
+        vec!["kay.e", " ", "a·i", ". ", "ne"]
+    );
 }
 
 pub trait IsLetter {
@@ -104,7 +176,7 @@ pub trait IsLetter {
 impl IsLetter for char {
     fn is_ainu_letter(&self) -> bool {
         self.is_alphabetic()
-            || "\u{3099}\u{309A}\u{309B}\u{309C}\u{FF9E}\u{FF9F}’'=".contains(*self)
+            || "\u{3099}\u{309A}\u{309B}\u{309C}\u{FF9E}\u{FF9F}\u{305}’'=".contains(*self)
     }
 }
 
@@ -115,3 +187,12 @@ fn test_is_ainu_letter() {
     assert_eq!('あ'.is_ainu_letter(), true);
     assert_eq!('🐱'.is_ainu_letter(), false);
 }
+
+/// Check if a word produced by [`SplitIntoWords`] is an Ainu word, i.e. made of
+/// letters, possibly with hiatus marks between them.
+pub fn is_ainu_word(word: &str) -> bool {
+    word.chars().next().is_some_and(|c| c.is_ainu_letter())
+        && word
+            .chars()
+            .all(|c| c.is_ainu_letter() || HIATUS_MARKS.contains(c))
+}
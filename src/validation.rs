@@ -0,0 +1,221 @@
+use crate::conversion::cyrillic::convert_cyrl_to_latn;
+use crate::conversion::katakana::convert_kana_to_latn;
+use crate::conversion::latin::{CONSONANTS, HIATUS_MARKS, VOWELS};
+use crate::detection::detect;
+use crate::util::remove_acute_accent;
+use crate::{Dialect, Script};
+use std::fmt;
+use std::ops::Range;
+use unicode_normalization::UnicodeNormalization;
+
+/// Consonant-vowel pairs that do not occur in Ainu.
+const IMPOSSIBLE_SYLLABLES: [&str; 3] = ["ti", "wu", "yi"];
+
+/// Kinds of phonotactic issues found by [`validate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PhonotacticIssueKind {
+    /// More consonants in a row than a coda followed by an onset (`*aktpa`)
+    Cluster,
+    /// A consonant that cannot close a syllable (`*-c`, and `*-h` outside Sakhalin)
+    IllegalCoda,
+    /// A consonant-vowel pair that does not occur (`ti`, `wu`, `yi`)
+    ImpossibleSyllable,
+    /// A character that is not part of the Ainu alphabet
+    StrayCharacter,
+    /// A word without a vowel
+    NoVowel,
+}
+
+impl PhonotacticIssueKind {
+    /// A short explanation of the issue.
+    pub fn description(&self) -> &'static str {
+        match self {
+            PhonotacticIssueKind::Cluster => "disallowed consonant cluster",
+            PhonotacticIssueKind::IllegalCoda => "illegal syllable coda",
+            PhonotacticIssueKind::ImpossibleSyllable => "impossible syllable",
+            PhonotacticIssueKind::StrayCharacter => "stray character",
+            PhonotacticIssueKind::NoVowel => "word without a vowel",
+        }
+    }
+}
+
+/// A phonotactic issue found by [`validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PhonotacticIssue {
+    /// What is wrong.
+    pub kind: PhonotacticIssueKind,
+    /// Byte range of the offending letters, in the word for Latin input and in its
+    /// romanized reading for Katakana and Cyrillic input.
+    pub span: Range<usize>,
+    /// The offending letters, romanized.
+    pub text: String,
+}
+
+impl fmt::Display for PhonotacticIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} `{}` at {}..{}",
+            self.kind.description(),
+            self.text,
+            self.span.start,
+            self.span.end
+        )
+    }
+}
+
+/// Check an Ainu word against the (C)V(C) syllable structure
+///
+/// The word may be written in Latin, Katakana or Cyrillic; Katakana and Cyrillic are
+/// read with [`convert_kana_to_latn`](crate::convert_kana_to_latn) and
+/// [`convert_cyrl_to_latn`](crate::convert_cyrl_to_latn) first. Codas are checked
+/// against the Hokkaido dialects.
+///
+/// # Arguments
+///
+/// * `word` - A string slice that holds the word.
+///
+/// # Returns
+///
+/// * `Vec<PhonotacticIssue>` - The issues found, in order. An empty vector means the
+///   word is well-formed.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{validate, PhonotacticIssueKind};
+/// assert!(validate("irankarapte").is_empty());
+/// assert!(validate("ku=kor").is_empty());
+///
+/// let issues = validate("tiac");
+/// assert_eq!(issues[0].kind, PhonotacticIssueKind::ImpossibleSyllable);
+/// assert_eq!(issues[0].span, 0..2);
+/// assert_eq!(issues[1].kind, PhonotacticIssueKind::IllegalCoda);
+/// assert_eq!(issues[1].span, 3..4);
+/// ```
+pub fn validate(word: &str) -> Vec<PhonotacticIssue> {
+    validate_with(word, Dialect::default())
+}
+
+/// Check an Ainu word against the (C)V(C) syllable structure of the given dialect
+///
+/// Sakhalin dialects allow `h` as a coda.
+///
+/// # Arguments
+///
+/// * `word` - A string slice that holds the word.
+/// * `dialect` - The dialect whose codas are allowed.
+///
+/// # Returns
+///
+/// * `Vec<PhonotacticIssue>` - The issues found, in order.
+///
+/// # Example
+///
+/// ```
+/// use ainconv::{validate_with, Dialect};
+/// assert!(validate_with("cah", Dialect::Sakhalin).is_empty());
+/// assert!(!validate_with("cah", Dialect::Hokkaido).is_empty());
+/// ```
+pub fn validate_with(word: &str, dialect: Dialect) -> Vec<PhonotacticIssue> {
+    let latn = match detect(word) {
+        Script::Kana => convert_kana_to_latn(word),
+        Script::Cyrl => convert_cyrl_to_latn(word),
+        _ => word.to_owned(),
+    };
+    let latn = latn.nfc().collect::<String>();
+
+    let mut issues = Vec::new();
+    let issue = |kind, span: Range<usize>| PhonotacticIssue {
+        kind,
+        text: latn[span.clone()].to_owned(),
+        span,
+    };
+
+    // Letters of the word with their spans, unaccented and in lower case
+    let mut letters: Vec<(Range<usize>, char)> = Vec::new();
+    for (i, c) in latn.char_indices() {
+        let span = i..i + c.len_utf8();
+        let lower = remove_acute_accent(&c.to_lowercase().collect::<String>());
+        let base = lower.chars().next().unwrap_or(c);
+        if base == '=' || HIATUS_MARKS.contains(base) {
+            continue;
+        }
+        if VOWELS.contains(base) || CONSONANTS.contains(base) {
+            letters.push((span, base));
+        } else {
+            issues.push(issue(PhonotacticIssueKind::StrayCharacter, span));
+        }
+    }
+
+    let is_vowel = |i: usize| VOWELS.contains(letters[i].1);
+    if !letters.is_empty() && !(0..letters.len()).any(is_vowel) {
+        issues.push(issue(PhonotacticIssueKind::NoVowel, 0..latn.len()));
+        return issues;
+    }
+
+    let mut start = 0;
+    while start < letters.len() {
+        if is_vowel(start) {
+            if let Some((span, c)) = start.checked_sub(1).map(|i| &letters[i]) {
+                let syllable = format!("{}{}", c, letters[start].1);
+                if !is_vowel(start - 1) && IMPOSSIBLE_SYLLABLES.contains(&syllable.as_str()) {
+                    let span = span.start..letters[start].0.end;
+                    issues.push(issue(PhonotacticIssueKind::ImpossibleSyllable, span));
+                }
+            }
+            start += 1;
+            continue;
+        }
+
+        let end = (start..letters.len())
+            .find(|&i| is_vowel(i))
+            .unwrap_or(letters.len());
+        let (at_start, at_end) = (start == 0, end == letters.len());
+        let allowed = if at_start || at_end { 1 } else { 2 };
+        if end - start > allowed {
+            let span = letters[start].0.start..letters[end - 1].0.end;
+            issues.push(issue(PhonotacticIssueKind::Cluster, span));
+        }
+
+        let coda = &letters[start];
+        let is_coda = !at_start && (at_end || end - start > 1);
+        let is_illegal = coda.1 == 'c' || (coda.1 == 'h' && dialect != Dialect::Sakhalin);
+        if is_coda && is_illegal {
+            issues.push(issue(PhonotacticIssueKind::IllegalCoda, coda.0.clone()));
+        }
+
+        start = end;
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reports_clusters_and_stray_characters() {
+        let issues = validate("aktpa");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].kind, PhonotacticIssueKind::Cluster);
+        assert_eq!(issues[0].text, "ktp");
+
+        let issues = validate("kamuy!");
+        assert_eq!(issues[0].kind, PhonotacticIssueKind::StrayCharacter);
+        assert_eq!(issues[0].span, 5..6);
+
+        assert_eq!(validate("nn")[0].kind, PhonotacticIssueKind::NoVowel);
+    }
+
+    #[test]
+    fn validates_kana_and_cyrillic() {
+        assert!(validate("イランカラㇷ゚テ").is_empty());
+        assert!(validate("айну").is_empty());
+        assert_eq!(
+            validate("ву")[0].kind,
+            PhonotacticIssueKind::ImpossibleSyllable
+        );
+    }
+}
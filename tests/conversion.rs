@@ -42,8 +42,8 @@ struct RobustnessCase {
     latn: String,
 }
 
-/* maciya -> мация TODO: мачия? */
-/* acapo -> ацапо TODO: ачапо? */
+/* maciya -> мация (мачия with CyrillicConvention::Russian) */
+/* acapo -> ацапо (ачапо with CyrillicConvention::Russian) */
 
 #[test]
 fn test_convert_latn_to_kana() {